@@ -0,0 +1,282 @@
+use crate::scip::ScipPtr;
+use crate::{ffi, Model, Solution, Solving, Variable};
+use scip_sys::{SCIP_Result, SCIP_BENDERSENFOTYPE};
+use std::rc::Rc;
+
+/// The name of SCIP's default Benders' decomposition, see
+/// [`Model::add_benders_subproblems`](crate::Model::add_benders_subproblems).
+pub(crate) const DEFAULT_BENDERS: &str = "default";
+
+/// A trait for defining custom cuts of a Benders' decomposition.
+///
+/// The cut is called after a subproblem was solved for a solution of the master problem. It can add optimality or
+/// feasibility cuts to the master problem, e.g. with `master.add_cons`.
+pub trait BendersCut {
+    /// Generates a cut from a solved subproblem.
+    ///
+    /// # Arguments
+    /// * `master` - the master problem in `Solving` stage.
+    /// * `benders` - the Benders' decomposition the cut belongs to, giving access to the subproblems.
+    /// * `sol` - the master solution the subproblem was solved for, the current LP or pseudo solution if its
+    ///   `inner` pointer is null.
+    /// * `probnumber` - the index of the subproblem.
+    /// * `enfo_type` - the point in the solving process at which the subproblem was solved.
+    ///
+    /// # Returns
+    /// * `BendersCutResult` indicating the result of the cut generation.
+    fn execute(
+        &mut self,
+        master: Model<Solving>,
+        benders: SCIPBenders,
+        sol: Solution,
+        probnumber: usize,
+        enfo_type: BendersEnfoType,
+    ) -> BendersCutResult;
+}
+
+/// The point in the solving process at which the Benders' subproblems are solved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BendersEnfoType {
+    /// Enforcing an LP solution of the master problem
+    Lp,
+    /// Enforcing a relaxation solution of the master problem
+    Relax,
+    /// Enforcing a pseudo solution of the master problem
+    Pseudo,
+    /// Checking a solution of the master problem for feasibility
+    Check,
+}
+
+impl From<SCIP_BENDERSENFOTYPE> for BendersEnfoType {
+    fn from(enfo_type: SCIP_BENDERSENFOTYPE) -> Self {
+        match enfo_type {
+            ffi::SCIP_BendersEnfoType_SCIP_BENDERSENFOTYPE_LP => BendersEnfoType::Lp,
+            ffi::SCIP_BendersEnfoType_SCIP_BENDERSENFOTYPE_RELAX => BendersEnfoType::Relax,
+            ffi::SCIP_BendersEnfoType_SCIP_BENDERSENFOTYPE_PSEUDO => BendersEnfoType::Pseudo,
+            ffi::SCIP_BendersEnfoType_SCIP_BENDERSENFOTYPE_CHECK => BendersEnfoType::Check,
+            _ => panic!("Unknown SCIP Benders' enforcement type"),
+        }
+    }
+}
+
+/// The result of a Benders' cut.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BendersCutResult {
+    /// The cut was not run
+    DidNotRun,
+    /// The cut was run, but no cut could be generated, e.g. because of an error
+    DidNotFind,
+    /// The cut was run and the master solution is feasible, so no cut is needed
+    Feasible,
+    /// Added a constraint to the master problem
+    ConsAdded,
+    /// Added a cutting plane to the LP of the master problem
+    Separated,
+}
+
+impl From<BendersCutResult> for SCIP_Result {
+    fn from(val: BendersCutResult) -> Self {
+        match val {
+            BendersCutResult::DidNotRun => ffi::SCIP_Result_SCIP_DIDNOTRUN,
+            BendersCutResult::DidNotFind => ffi::SCIP_Result_SCIP_DIDNOTFIND,
+            BendersCutResult::Feasible => ffi::SCIP_Result_SCIP_FEASIBLE,
+            BendersCutResult::ConsAdded => ffi::SCIP_Result_SCIP_CONSADDED,
+            BendersCutResult::Separated => ffi::SCIP_Result_SCIP_SEPARATED,
+        }
+    }
+}
+
+/// A wrapper struct for the internal ffi::SCIP_BENDERS
+pub struct SCIPBenders {
+    pub(crate) raw: *mut ffi::SCIP_BENDERS,
+    pub(crate) scip: Rc<ScipPtr>,
+}
+
+impl SCIPBenders {
+    /// Returns the internal raw pointer of the Benders' decomposition.
+    pub fn inner(&self) -> *mut ffi::SCIP_BENDERS {
+        self.raw
+    }
+
+    /// Returns the name of the Benders' decomposition.
+    pub fn name(&self) -> String {
+        unsafe {
+            let name_ptr = ffi::SCIPbendersGetName(self.raw);
+            let name = std::ffi::CStr::from_ptr(name_ptr).to_str().unwrap();
+            name.to_string()
+        }
+    }
+
+    /// Returns the number of subproblems of the Benders' decomposition.
+    pub fn n_subproblems(&self) -> usize {
+        unsafe { ffi::SCIPbendersGetNSubproblems(self.raw) as usize }
+    }
+
+    /// Returns the subproblem with the given index, or `None` if the index is out of range.
+    ///
+    /// The subproblem is solved for the current master solution when a cut is executed.
+    pub fn subproblem(&self, probnumber: usize) -> Option<Model<Solving>> {
+        if probnumber >= self.n_subproblems() {
+            return None;
+        }
+        let raw = unsafe { ffi::SCIPbendersSubproblem(self.raw, probnumber as i32) };
+        if raw.is_null() {
+            return None;
+        }
+        Some(Model {
+            scip: Rc::new(ScipPtr::from_raw(raw, true)),
+            state: Solving,
+        })
+    }
+
+    /// Returns the copy of a master variable in the given subproblem, or `None` if the variable does not link the
+    /// master problem to that subproblem.
+    pub fn subproblem_var(&self, var: &Variable, probnumber: usize) -> Option<Variable> {
+        if probnumber >= self.n_subproblems() {
+            return None;
+        }
+        let mut mapped = std::ptr::null_mut();
+        let retcode = unsafe {
+            ffi::SCIPgetBendersSubproblemVar(
+                self.scip.raw,
+                self.raw,
+                var.raw,
+                &mut mapped,
+                probnumber as i32,
+            )
+        };
+        if retcode != ffi::SCIP_Retcode_SCIP_OKAY || mapped.is_null() {
+            return None;
+        }
+        let subproblem = unsafe { ffi::SCIPbendersSubproblem(self.raw, probnumber as i32) };
+        Some(Variable {
+            raw: mapped,
+            scip: Rc::new(ScipPtr::from_raw(subproblem, true)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use crate::{ParamSetting, ProblemCreated};
+    use std::sync::{Arc, Mutex};
+
+    /// Creates the master problem min x with integer x, the Benders' decomposition adds the cost of the subproblem
+    /// to it.
+    fn master() -> Model<ProblemCreated> {
+        let mut model = Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .create_prob("master")
+            .set_presolving(ParamSetting::Off)
+            .set_heuristics(ParamSetting::Off)
+            .minimize();
+        model.add(var().int(0..=10).obj(1.0).name("x"));
+        model
+    }
+
+    /// Creates the subproblem min 2 z with z >= 3 - x, where x is linked to the master problem by its name.
+    fn subproblem() -> Model<ProblemCreated> {
+        let mut model = Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .create_prob("subproblem")
+            .set_presolving(ParamSetting::Off)
+            .minimize();
+        let x = model.add(var().cont(0.0..=10.0).obj(0.0).name("x"));
+        let z = model.add(var().cont(0.0..).obj(2.0).name("z"));
+        model.add(cons().coef(&x, 1.0).coef(&z, 1.0).ge(3.0));
+        model
+    }
+
+    #[test]
+    fn default_benders() {
+        let mut model = master();
+        model.add_benders_subproblems(vec![subproblem()]).unwrap();
+        let solved = model.solve();
+        assert_eq!(solved.status(), Status::Optimal);
+        // x + 2 max(3 - x, 0) is minimal for x = 3
+        assert!((solved.obj_val() - 3.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn send_with_shared_subproblem() {
+        let mut model = master();
+        let subproblem = subproblem();
+        let subproblem_vars = subproblem.vars();
+        model.add_benders_subproblems(vec![subproblem]).unwrap();
+
+        // the variables of the subproblem still refer to its SCIP instance
+        let err = model.solve_async().unwrap_err();
+        assert_eq!(err.retcode(), Retcode::InvalidCall);
+        drop(subproblem_vars);
+    }
+
+    struct CountingCut {
+        calls: Arc<Mutex<usize>>,
+    }
+
+    impl BendersCut for CountingCut {
+        fn execute(
+            &mut self,
+            master: Model<Solving>,
+            benders: SCIPBenders,
+            _sol: Solution,
+            probnumber: usize,
+            _enfo_type: BendersEnfoType,
+        ) -> BendersCutResult {
+            assert_eq!(benders.name(), "default");
+            assert_eq!(benders.n_subproblems(), 1);
+            assert!(benders.subproblem(probnumber).is_some());
+            let x = master
+                .vars()
+                .into_iter()
+                .find(|var| var.name().ends_with('x'))
+                .unwrap();
+            assert!(benders.subproblem_var(&x, probnumber).is_some());
+            *self.calls.lock().unwrap() += 1;
+            BendersCutResult::DidNotRun
+        }
+    }
+
+    #[test]
+    fn benders_cut() {
+        let calls = Arc::new(Mutex::new(0));
+        let mut model = master();
+        model.add_benders_subproblems(vec![subproblem()]).unwrap();
+        model
+            .include_benders_cut(
+                "counting",
+                "counts its calls",
+                100000,
+                true,
+                Box::new(CountingCut {
+                    calls: calls.clone(),
+                }),
+            )
+            .unwrap();
+        let solved = model.solve();
+        assert_eq!(solved.status(), Status::Optimal);
+        assert!((solved.obj_val() - 3.0).abs() < 1e-6);
+        assert!(*calls.lock().unwrap() > 0);
+    }
+
+    #[test]
+    fn cut_without_benders() {
+        let mut model = master();
+        let err = model
+            .include_benders_cut(
+                "counting",
+                "counts its calls",
+                0,
+                true,
+                Box::new(CountingCut {
+                    calls: Arc::new(Mutex::new(0)),
+                }),
+            )
+            .unwrap_err();
+        assert_eq!(err.retcode(), Retcode::InvalidCall);
+    }
+}
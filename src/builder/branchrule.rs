@@ -1,5 +1,5 @@
 use crate::builder::CanBeAddedToModel;
-use crate::{BranchRule, Model, ProblemCreated};
+use crate::{BranchRule, Error, Model, ProblemCreated};
 
 /// A builder for easily creating branch rules. It can be created using the `branch_rule` function.
 pub struct BranchRuleBuilder<R: BranchRule> {
@@ -100,19 +100,23 @@ pub fn branchrule<R: BranchRule>(rule: R) -> BranchRuleBuilder<R> {
 impl<R: BranchRule + 'static> CanBeAddedToModel for BranchRuleBuilder<R> {
     type Return = ();
 
-    fn add(self, model: &mut Model<ProblemCreated>) {
+    fn add(self, model: &mut Model<ProblemCreated>) -> Self::Return {
+        self.try_add(model).expect("Failed to add to the model")
+    }
+
+    fn try_add(self, model: &mut Model<ProblemCreated>) -> Result<(), Error> {
         // Use empty strings as defaults if name or description are not provided.
         let name = self.name.unwrap_or_else(|| "".into());
         let desc = self.desc.unwrap_or_else(|| "".into());
         let rule_box = Box::new(self.rule);
-        model.include_branch_rule(
+        model.try_include_branch_rule(
             &name,
             &desc,
             self.priority,
             self.maxdepth,
             self.maxbounddist,
             rule_box,
-        );
+        )
     }
 }
 
@@ -1,5 +1,7 @@
 use crate::builder::CanBeAddedToModel;
-use crate::{Constraint, Model, ModelWithProblem, ProblemCreated, ProblemOrSolving, Variable};
+use crate::{
+    Constraint, Error, Model, ModelWithProblem, ProblemCreated, ProblemOrSolving, Variable,
+};
 
 /// A builder for creating constraints.
 #[derive(Debug)]
@@ -73,6 +75,10 @@ impl<'a> ConsBuilder<'a> {
 impl CanBeAddedToModel for ConsBuilder<'_> {
     type Return = Constraint;
     fn add(self, model: &mut Model<ProblemCreated>) -> Self::Return {
+        self.try_add(model).expect("Failed to add to the model")
+    }
+
+    fn try_add(self, model: &mut Model<ProblemCreated>) -> Result<Self::Return, Error> {
         let mut vars = Vec::new();
         let mut coefs = Vec::new();
         for (var, coef) in self.coefs {
@@ -84,7 +90,7 @@ impl CanBeAddedToModel for ConsBuilder<'_> {
             let n_cons = model.n_conss();
             format!("cons{}", n_cons)
         });
-        model.add_cons(vars, &coefs, self.lhs, self.rhs, &name)
+        model.try_add_cons(vars, &coefs, self.lhs, self.rhs, &name)
     }
 }
 
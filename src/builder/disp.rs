@@ -0,0 +1,143 @@
+use crate::builder::CanBeAddedToModel;
+use crate::{DisplayColumn, DisplayStatus, Error, Model, ProblemCreated};
+
+/// A builder for easily creating display columns. It can be created using the `display_column` function.
+pub struct DisplayColumnBuilder<D: DisplayColumn> {
+    name: Option<String>,
+    desc: Option<String>,
+    header: Option<String>,
+    width: usize,
+    priority: i32,
+    position: i32,
+    stripline: bool,
+    status: DisplayStatus,
+    column: D,
+}
+
+impl<D: DisplayColumn> DisplayColumnBuilder<D> {
+    /// Creates a new `DisplayColumnBuilder` with the given display column.
+    ///
+    /// # Defaults
+    /// - `name`: empty string
+    /// - `desc`: empty string
+    /// - `header`: the name of the column
+    /// - `width`: 10
+    /// - `priority`: 100000
+    /// - `position`: 100000 (after all default columns)
+    /// - `stripline`: true
+    /// - `status`: `DisplayStatus::Auto`
+    pub fn new(column: D) -> Self {
+        DisplayColumnBuilder {
+            name: None,
+            desc: None,
+            header: None,
+            width: 10,
+            priority: 100000,
+            position: 100000,
+            stripline: true,
+            status: DisplayStatus::Auto,
+            column,
+        }
+    }
+
+    /// Sets the name of the display column.
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// Sets the description of the display column.
+    pub fn desc(mut self, desc: &str) -> Self {
+        self.desc = Some(desc.to_string());
+        self
+    }
+
+    /// Sets the header of the display column.
+    pub fn header(mut self, header: &str) -> Self {
+        self.header = Some(header.to_string());
+        self
+    }
+
+    /// Sets the width of the display column (in characters).
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the priority of the display column; columns with lower priority are dropped first if the table is too wide.
+    pub fn priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Sets the relative position of the display column; columns with smaller positions are shown further left.
+    pub fn position(mut self, position: i32) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// Sets whether a vertical bar should be printed after the column.
+    pub fn stripline(mut self, stripline: bool) -> Self {
+        self.stripline = stripline;
+        self
+    }
+
+    /// Sets whether the display column is shown.
+    pub fn status(mut self, status: DisplayStatus) -> Self {
+        self.status = status;
+        self
+    }
+}
+
+/// Creates a new default `DisplayColumnBuilder` from a display column.
+/// This function allows you to start chaining:
+///
+/// ```rust
+/// use russcip::prelude::*;
+/// use russcip::{DisplayColumn, DisplayStatus, Solving};
+///
+/// struct MyColumn;
+/// impl DisplayColumn for MyColumn {
+///     fn output(&mut self, model: Model<Solving>) -> String {
+///         model.n_nodes().to_string()
+///     }
+/// }
+///
+/// let column = display_column(MyColumn)
+///     .name("mycolumn")
+///     .header("my col")
+///     .width(8)
+///     .position(100)
+///     .status(DisplayStatus::On);
+///
+/// let mut model = Model::default();
+/// model.add(column);
+/// ```
+pub fn display_column<D: DisplayColumn>(column: D) -> DisplayColumnBuilder<D> {
+    DisplayColumnBuilder::new(column)
+}
+
+impl<D: DisplayColumn + 'static> CanBeAddedToModel for DisplayColumnBuilder<D> {
+    type Return = ();
+
+    fn add(self, model: &mut Model<ProblemCreated>) -> Self::Return {
+        self.try_add(model).expect("Failed to add to the model")
+    }
+
+    fn try_add(self, model: &mut Model<ProblemCreated>) -> Result<(), Error> {
+        let name = self.name.unwrap_or_else(|| "".into());
+        let desc = self.desc.unwrap_or_else(|| "".into());
+        let header = self.header.unwrap_or_else(|| name.clone());
+        model.try_include_display_column(
+            &name,
+            &desc,
+            &header,
+            self.width,
+            self.priority,
+            self.position,
+            self.stripline,
+            self.status,
+            Box::new(self.column),
+        )
+    }
+}
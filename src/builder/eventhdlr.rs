@@ -1,5 +1,5 @@
 use crate::builder::CanBeAddedToModel;
-use crate::{Eventhdlr, Model, ProblemCreated};
+use crate::{Error, Eventhdlr, Model, ProblemCreated};
 
 /// A builder for easily creating event handlers. It can be created using the `eventhdlr` function.
 pub struct EventHdlrBuilder<E: Eventhdlr> {
@@ -63,10 +63,14 @@ pub fn eventhdlr<E: Eventhdlr>(ev: E) -> EventHdlrBuilder<E> {
 impl<E: Eventhdlr + 'static> CanBeAddedToModel for EventHdlrBuilder<E> {
     type Return = ();
 
-    fn add(self, model: &mut Model<ProblemCreated>) {
+    fn add(self, model: &mut Model<ProblemCreated>) -> Self::Return {
+        self.try_add(model).expect("Failed to add to the model")
+    }
+
+    fn try_add(self, model: &mut Model<ProblemCreated>) -> Result<(), Error> {
         let name = self.name.unwrap_or_else(|| "".into());
         let desc = self.desc.unwrap_or_else(|| "".into());
         let eventhdlr = Box::new(self.eventhdlr);
-        model.include_eventhdlr(&name, &desc, eventhdlr);
+        model.try_include_eventhdlr(&name, &desc, eventhdlr)
     }
 }
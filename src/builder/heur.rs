@@ -1,5 +1,5 @@
 use crate::builder::CanBeAddedToModel;
-use crate::{HeurTiming, Heuristic, Model, ProblemCreated};
+use crate::{Error, HeurTiming, Heuristic, Model, ProblemCreated};
 
 /// A builder for easily creating primal heuristics. It can be created using the `heur` function.
 pub struct HeurBuilder<H: Heuristic> {
@@ -134,13 +134,17 @@ pub fn heur<H: Heuristic>(heur: H) -> HeurBuilder<H> {
 impl<H: Heuristic + 'static> CanBeAddedToModel for HeurBuilder<H> {
     type Return = ();
 
-    fn add(self, model: &mut Model<ProblemCreated>) {
+    fn add(self, model: &mut Model<ProblemCreated>) -> Self::Return {
+        self.try_add(model).expect("Failed to add to the model")
+    }
+
+    fn try_add(self, model: &mut Model<ProblemCreated>) -> Result<(), Error> {
         let name = self.name.unwrap_or_else(|| "".into());
         let desc = self.desc.unwrap_or_else(|| "".into());
         let dispchar = self.dispchar.unwrap_or('?');
         let timing = self.timing.unwrap_or(HeurTiming::BEFORE_NODE);
         let heur_box = Box::new(self.heur);
-        model.include_heur(
+        model.try_include_heur(
             &name,
             &desc,
             self.priority,
@@ -151,6 +155,6 @@ impl<H: Heuristic + 'static> CanBeAddedToModel for HeurBuilder<H> {
             timing,
             self.usessubscip,
             heur_box,
-        );
+        )
     }
 }
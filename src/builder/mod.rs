@@ -2,6 +2,8 @@
 pub mod branchrule;
 /// This module contains `ConsBuilder` for easily creating constraints.
 pub mod cons;
+/// This module contains `DisplayColumnBuilder` for easily creating display columns.
+pub mod disp;
 /// This module contains `EventHdlrBuilder` for easily creating event handlers.
 pub mod eventhdlr;
 /// This module contains `HeurBuilder` for easily creating heuristics.
@@ -13,7 +15,7 @@ pub mod sepa;
 /// This module contains `VarBuilder` for easily creating variables.
 pub mod var;
 
-use crate::{Model, ProblemCreated};
+use crate::{Error, Model, ProblemCreated};
 
 /// A trait for adding two values together.
 pub trait CanBeAddedToModel {
@@ -21,8 +23,21 @@ pub trait CanBeAddedToModel {
     type Return;
     /// How to add the value to the model.
     fn add(self, model: &mut Model<ProblemCreated>) -> Self::Return;
+
+    /// Fallible version of [`add`](CanBeAddedToModel::add), returns an [`Error`] if the value cannot be added.
+    ///
+    /// The default implementation calls `add`, so it panics instead of returning an error for implementations
+    /// that do not override it.
+    fn try_add(self, model: &mut Model<ProblemCreated>) -> Result<Self::Return, Error>
+    where
+        Self: Sized,
+    {
+        Ok(self.add(model))
+    }
 }
 
+/// Adds the items in order. If an item cannot be added, the items before it stay in the model and the returned
+/// [`Error`] holds the position of the failing item, see [`Error::index`].
 impl<T, I> CanBeAddedToModel for I
 where
     T: CanBeAddedToModel,
@@ -32,4 +47,11 @@ where
     fn add(self, model: &mut Model<ProblemCreated>) -> Self::Return {
         self.into_iter().map(|x| x.add(model)).collect()
     }
+
+    fn try_add(self, model: &mut Model<ProblemCreated>) -> Result<Self::Return, Error> {
+        self.into_iter()
+            .enumerate()
+            .map(|(index, x)| x.try_add(model).map_err(|err| err.with_index(index)))
+            .collect()
+    }
 }
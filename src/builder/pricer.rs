@@ -1,5 +1,5 @@
 use crate::builder::CanBeAddedToModel;
-use crate::{Model, Pricer, ProblemCreated};
+use crate::{Error, Model, Pricer, ProblemCreated};
 
 /// A builder for easily creating pricers. It can be created using the `pricer` function.
 pub struct PricerBuilder<P: Pricer> {
@@ -89,11 +89,15 @@ pub fn pricer<P: Pricer>(pricer: P) -> PricerBuilder<P> {
 impl<P: Pricer + 'static> CanBeAddedToModel for PricerBuilder<P> {
     type Return = ();
 
-    fn add(self, model: &mut Model<ProblemCreated>) {
+    fn add(self, model: &mut Model<ProblemCreated>) -> Self::Return {
+        self.try_add(model).expect("Failed to add to the model")
+    }
+
+    fn try_add(self, model: &mut Model<ProblemCreated>) -> Result<(), Error> {
         let name = self.name.unwrap_or_else(|| "".into());
         let desc = self.desc.unwrap_or_else(|| "".into());
         let pricer_box = Box::new(self.pricer);
 
-        model.include_pricer(&name, &desc, self.priority, self.delay, pricer_box);
+        model.try_include_pricer(&name, &desc, self.priority, self.delay, pricer_box)
     }
 }
@@ -1,5 +1,5 @@
 use crate::builder::CanBeAddedToModel;
-use crate::{Model, ProblemCreated, Separator};
+use crate::{Error, Model, ProblemCreated, Separator};
 
 /// A builder for easily creating separators. It can be easily created using the `sepa` function.
 pub struct SepaBuilder<S: Separator> {
@@ -109,14 +109,18 @@ impl<S: Separator> SepaBuilder<S> {
 
 impl<S: Separator + 'static> CanBeAddedToModel for SepaBuilder<S> {
     type Return = ();
-    fn add(self, model: &mut Model<ProblemCreated>) {
+    fn add(self, model: &mut Model<ProblemCreated>) -> Self::Return {
+        self.try_add(model).expect("Failed to add to the model")
+    }
+
+    fn try_add(self, model: &mut Model<ProblemCreated>) -> Result<(), Error> {
         let name = self.name.clone().unwrap_or("".into());
         let desc = self.desc.clone().unwrap_or("".into());
 
         let sepa = self.sepa;
         let sepa = Box::new(sepa);
 
-        model.include_separator(
+        model.try_include_separator(
             &name,
             &desc,
             self.priority,
@@ -125,6 +129,6 @@ impl<S: Separator + 'static> CanBeAddedToModel for SepaBuilder<S> {
             self.usesubscip,
             self.delay,
             sepa,
-        );
+        )
     }
 }
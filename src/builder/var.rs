@@ -1,5 +1,5 @@
 use crate::builder::CanBeAddedToModel;
-use crate::{Model, ModelWithProblem, ProblemCreated, VarType, Variable};
+use crate::{Error, Model, ModelWithProblem, ProblemCreated, VarType, Variable};
 use std::ops::RangeBounds;
 
 /// A builder for variables. It can be easily created using the `var` function.
@@ -169,13 +169,17 @@ impl<'a> VarBuilder<'a> {
 
 impl CanBeAddedToModel for VarBuilder<'_> {
     type Return = Variable;
-    fn add(self, model: &mut Model<ProblemCreated>) -> Variable {
+    fn add(self, model: &mut Model<ProblemCreated>) -> Self::Return {
+        self.try_add(model).expect("Failed to add to the model")
+    }
+
+    fn try_add(self, model: &mut Model<ProblemCreated>) -> Result<Variable, Error> {
         let name = self.name.map(|s| s.to_string()).unwrap_or_else(|| {
             let n_vars = model.n_vars();
             format!("x{}", n_vars)
         });
 
-        model.add_var(self.lb, self.ub, self.obj, &name, self.var_type)
+        model.try_add_var(self.lb, self.ub, self.obj, &name, self.var_type)
     }
 }
 
@@ -1,7 +1,7 @@
 use crate::row::BasisStatus;
 use crate::row::Row;
 use crate::scip::ScipPtr;
-use crate::{ffi, Variable};
+use crate::{ffi, Stage, Variable};
 use std::rc::Rc;
 
 /// A column in the LP relaxation.u
@@ -69,6 +69,18 @@ impl Col {
         unsafe { ffi::SCIPcolGetMaxPrimsol(self.raw) }
     }
 
+    /// Returns the reduced cost of the column in the LP of the current node, or `None` if the LP of the current node
+    /// was not solved (or SCIP is not in the `Solving` stage). After solving, the reduced costs are available through
+    /// `Model::lp_redcosts`.
+    pub fn redcost(&self) -> Option<f64> {
+        if self.scip.stage() != Stage::Solving
+            || (unsafe { ffi::SCIPhasCurrentNodeLP(self.scip.raw) }) == 0
+        {
+            return None;
+        }
+        Some(unsafe { ffi::SCIPgetColRedcost(self.scip.raw, self.raw) })
+    }
+
     /// Returns the basis status of a column in the LP solution.
     pub fn basis_status(&self) -> BasisStatus {
         unsafe { ffi::SCIPcolGetBasisStatus(self.raw) }.into()
@@ -0,0 +1,91 @@
+use crate::{ffi, Model, Solving};
+
+/// A trait for defining custom columns of SCIP's progress table (the node log).
+pub trait DisplayColumn {
+    /// Returns the content of the column for the current line of the progress table.
+    ///
+    /// # Arguments
+    /// * `model` - the current model of the SCIP instance in `Solving` stage
+    ///
+    /// The returned string is right-aligned to the column width and truncated if it is longer.
+    fn output(&mut self, model: Model<Solving>) -> String;
+}
+
+/// Whether a display column is shown in the progress table.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DisplayStatus {
+    /// The column is never shown.
+    Off,
+    /// The column is shown if there is enough space in the table.
+    Auto,
+    /// The column is always shown.
+    On,
+}
+
+impl From<DisplayStatus> for ffi::SCIP_DISPSTATUS {
+    fn from(status: DisplayStatus) -> Self {
+        match status {
+            DisplayStatus::Off => ffi::SCIP_DispStatus_SCIP_DISPSTATUS_OFF,
+            DisplayStatus::Auto => ffi::SCIP_DispStatus_SCIP_DISPSTATUS_AUTO,
+            DisplayStatus::On => ffi::SCIP_DispStatus_SCIP_DISPSTATUS_ON,
+        }
+    }
+}
+
+/// Fits the output of a display column to the given width.
+pub(crate) fn fit_to_width(output: &str, width: usize) -> String {
+    let truncated: String = output.chars().take(width).collect();
+    format!("{truncated:>width$}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use crate::MessageLevel;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    struct NodesColumn;
+
+    impl DisplayColumn for NodesColumn {
+        fn output(&mut self, model: Model<Solving>) -> String {
+            format!("n{}", model.n_nodes())
+        }
+    }
+
+    #[test]
+    fn custom_column() {
+        let lines = Rc::new(RefCell::new(Vec::new()));
+        let lines_clone = lines.clone();
+        let mut model = Model::new()
+            .set_message_handler(move |level: MessageLevel, msg: &str| {
+                if level == MessageLevel::Info {
+                    lines_clone.borrow_mut().push(msg.to_string())
+                }
+            })
+            .include_default_plugins()
+            .read_prob("data/test/gen-ip054.mps")
+            .unwrap();
+
+        model.add(
+            display_column(NodesColumn)
+                .name("mynodes")
+                .header("mynodes")
+                .width(8)
+                .status(DisplayStatus::On),
+        );
+        let solved = model.solve();
+        assert_eq!(solved.status(), Status::Optimal);
+
+        let lines = lines.borrow();
+        assert!(lines.iter().any(|line| line.contains("mynodes")));
+        assert!(lines.iter().any(|line| line.contains("      n1")));
+    }
+
+    #[test]
+    fn fit_output() {
+        assert_eq!(fit_to_width("abc", 5), "  abc");
+        assert_eq!(fit_to_width("abcdef", 4), "abcd");
+    }
+}
@@ -0,0 +1,205 @@
+use crate::{Error, Model, Row, Solving, Variable};
+
+/// The result of solving the LP in diving or probing mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LpSolveResult {
+    /// Whether an unresolved error occurred while solving the LP.
+    pub lp_error: bool,
+    /// Whether the LP was infeasible or its objective exceeded the cutoff bound.
+    pub cutoff: bool,
+}
+
+/// An LP dive, obtained through [`Model::start_dive`](crate::Model::start_dive).
+///
+/// While diving, the bounds and objective coefficients of the columns and the sides of the rows in the LP can be
+/// changed and the LP can be resolved, without affecting the branch-and-bound tree. The dive is ended, and all
+/// changes are undone, by [`end`](DiveGuard::end) or when the guard is dropped.
+#[derive(Debug)]
+pub struct DiveGuard<'a> {
+    model: &'a mut Model<Solving>,
+}
+
+impl<'a> DiveGuard<'a> {
+    pub(crate) fn new(model: &'a mut Model<Solving>) -> Self {
+        DiveGuard { model }
+    }
+
+    /// Returns the model, e.g. to read the LP solution of the dive through [`Col::primal_sol`](crate::Col::primal_sol).
+    pub fn model(&self) -> &Model<Solving> {
+        self.model
+    }
+
+    /// Changes the lower bound of a variable in the dive.
+    pub fn chg_var_lb(&mut self, var: &Variable, lb: f64) -> Result<(), Error> {
+        self.model
+            .scip
+            .try_call("chg_var_lb_dive", Some(&var.name()), |scip| {
+                scip.chg_var_lb_dive(var, lb)
+            })
+    }
+
+    /// Changes the upper bound of a variable in the dive.
+    pub fn chg_var_ub(&mut self, var: &Variable, ub: f64) -> Result<(), Error> {
+        self.model
+            .scip
+            .try_call("chg_var_ub_dive", Some(&var.name()), |scip| {
+                scip.chg_var_ub_dive(var, ub)
+            })
+    }
+
+    /// Changes the objective coefficient of a variable in the dive.
+    pub fn chg_var_obj(&mut self, var: &Variable, obj: f64) -> Result<(), Error> {
+        self.model
+            .scip
+            .try_call("chg_var_obj_dive", Some(&var.name()), |scip| {
+                scip.chg_var_obj_dive(var, obj)
+            })
+    }
+
+    /// Returns the lower bound of a variable in the dive.
+    pub fn var_lb(&self, var: &Variable) -> f64 {
+        self.model.scip.var_lb_dive(var)
+    }
+
+    /// Returns the upper bound of a variable in the dive.
+    pub fn var_ub(&self, var: &Variable) -> f64 {
+        self.model.scip.var_ub_dive(var)
+    }
+
+    /// Returns the objective coefficient of a variable in the dive.
+    pub fn var_obj(&self, var: &Variable) -> f64 {
+        self.model.scip.var_obj_dive(var)
+    }
+
+    /// Changes the left hand side of a row in the dive.
+    pub fn chg_row_lhs(&mut self, row: &Row, lhs: f64) -> Result<(), Error> {
+        self.model
+            .scip
+            .try_call("chg_row_lhs_dive", Some(&row.name()), |scip| {
+                scip.chg_row_lhs_dive(row, lhs)
+            })
+    }
+
+    /// Changes the right hand side of a row in the dive.
+    pub fn chg_row_rhs(&mut self, row: &Row, rhs: f64) -> Result<(), Error> {
+        self.model
+            .scip
+            .try_call("chg_row_rhs_dive", Some(&row.name()), |scip| {
+                scip.chg_row_rhs_dive(row, rhs)
+            })
+    }
+
+    /// Changes the cutoff bound of the dive.
+    pub fn chg_cutoff_bound(&mut self, cutoff_bound: f64) -> Result<(), Error> {
+        self.model
+            .scip
+            .try_call("chg_cutoff_bound_dive", None, |scip| {
+                scip.chg_cutoff_bound_dive(cutoff_bound)
+            })
+    }
+
+    /// Solves the LP of the dive, without separation or pricing.
+    ///
+    /// # Arguments
+    ///
+    /// * `iteration_limit` - The maximal number of LP iterations, or `None` for no limit.
+    pub fn solve_lp(&mut self, iteration_limit: Option<usize>) -> Result<LpSolveResult, Error> {
+        self.model.scip.try_call("solve_dive_lp", None, |scip| {
+            scip.solve_dive_lp(iteration_limit)
+        })
+    }
+
+    /// Returns the objective value of the LP solved last.
+    pub fn lp_obj_val(&self) -> f64 {
+        self.model.scip.lp_obj_val()
+    }
+
+    /// Ends the dive and undoes all changes, returning an [`Error`] if SCIP fails to do so.
+    pub fn end(self) -> Result<(), Error> {
+        let result = self.end_dive();
+        // the dive is over, dropping the guard would end it a second time
+        std::mem::forget(self);
+        result
+    }
+
+    fn end_dive(&self) -> Result<(), Error> {
+        self.model
+            .scip
+            .try_call("end_dive", None, |scip| scip.end_dive())
+    }
+}
+
+impl Drop for DiveGuard<'_> {
+    fn drop(&mut self) {
+        // panicking here could abort the process while unwinding, so failures are only reported as a warning
+        if let Err(err) = self.end_dive() {
+            self.model.scip.warning_message(&err.to_string());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use crate::{HeurResult, HeurTiming, Heuristic, ParamSetting};
+    use std::sync::{Arc, Mutex};
+
+    struct DivingHeur {
+        objs: Arc<Mutex<Vec<f64>>>,
+    }
+
+    impl Heuristic for DivingHeur {
+        fn execute(
+            &mut self,
+            mut model: Model<Solving>,
+            _timing: HeurTiming,
+            _node_inf: bool,
+        ) -> HeurResult {
+            let vars = model.vars();
+            let x1 = &vars[0];
+            let x2 = &vars[1];
+            assert!(!model.in_dive());
+            let ub = x1.col().unwrap().ub();
+
+            let mut dive = model.start_dive().unwrap();
+            assert!(dive.model().in_dive());
+            dive.chg_var_ub(x1, 10.0).unwrap();
+            assert_eq!(dive.var_ub(x1), 10.0);
+            let res = dive.solve_lp(None).unwrap();
+            assert!(!res.lp_error && !res.cutoff);
+            assert!((x1.col().unwrap().primal_sol() - 10.0).abs() < 1e-6);
+            assert!((x2.col().unwrap().primal_sol() - 35.0).abs() < 1e-6);
+            self.objs.lock().unwrap().push(dive.lp_obj_val());
+            dive.end().unwrap();
+
+            assert!(!model.in_dive());
+            assert_eq!(x1.col().unwrap().ub(), ub);
+            HeurResult::DidNotRun
+        }
+    }
+
+    #[test]
+    fn dive() {
+        let mut model = Model::new()
+            .hide_output()
+            .set_presolving(ParamSetting::Off)
+            .include_default_plugins()
+            .read_prob("data/test/simple.lp")
+            .unwrap();
+
+        let objs = Arc::new(Mutex::new(Vec::new()));
+        model.add(
+            heur(DivingHeur { objs: objs.clone() })
+                .name("diving_heur")
+                .timing(HeurTiming::AFTER_LP_NODE),
+        );
+        let solved = model.solve();
+        assert_eq!(solved.status(), Status::Optimal);
+        assert_eq!(solved.obj_val(), 200.0);
+
+        let objs = objs.lock().unwrap();
+        assert!(!objs.is_empty());
+        assert!(objs.iter().all(|obj| (obj - 170.0).abs() < 1e-6));
+    }
+}
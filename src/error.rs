@@ -0,0 +1,192 @@
+use crate::message::{capture_errors, with_error_route};
+use crate::scip::ScipPtr;
+use crate::{Retcode, Stage};
+use std::fmt;
+
+/// An error returned by the fallible methods of `Model`.
+///
+/// Besides the `Retcode` returned by SCIP, it records which operation failed, on which object, in which stage
+/// the SCIP instance was at that time, and the error message SCIP printed while the operation was running.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    retcode: Retcode,
+    operation: &'static str,
+    name: Option<String>,
+    stage: Stage,
+    message: String,
+    index: Option<usize>,
+}
+
+/// An alias of [`Error`], for code where the name would clash with `std::error::Error` or other error types.
+pub type RusscipError = Error;
+
+impl Error {
+    pub(crate) fn new(
+        retcode: Retcode,
+        operation: &'static str,
+        name: Option<&str>,
+        stage: Stage,
+    ) -> Self {
+        Error {
+            retcode,
+            operation,
+            name: name.map(str::to_string),
+            stage,
+            message: String::new(),
+            index: None,
+        }
+    }
+
+    pub(crate) fn with_index(mut self, index: usize) -> Self {
+        self.index = Some(index);
+        self
+    }
+
+    pub(crate) fn with_message(mut self, message: &str) -> Self {
+        self.message = message
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .collect::<Vec<_>>()
+            .join("\n");
+        self
+    }
+
+    /// Returns the return code of the failing SCIP call.
+    pub fn retcode(&self) -> Retcode {
+        self.retcode
+    }
+
+    /// Returns the name of the operation that failed, e.g. `add_cons`.
+    pub fn operation(&self) -> &str {
+        self.operation
+    }
+
+    /// Returns the name of the object (variable, constraint, file, ...) the operation was called for, if any.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Returns the stage of the SCIP instance when the error occurred.
+    pub fn stage(&self) -> Stage {
+        self.stage
+    }
+
+    /// Returns the position of the failing item when adding a collection through [`Model::try_add`](crate::Model::try_add),
+    /// or `None` otherwise.
+    pub fn index(&self) -> Option<usize> {
+        self.index
+    }
+
+    /// Returns the error message SCIP printed during the failing operation, or an empty string if there was none.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.operation)?;
+        if let Some(name) = &self.name {
+            write!(f, " `{name}`")?;
+        }
+        if let Some(index) = self.index {
+            write!(f, " (item {index})")?;
+        }
+        write!(
+            f,
+            " failed with retcode {:?} in stage {:?}",
+            self.retcode, self.stage
+        )?;
+        if !self.message.is_empty() {
+            write!(f, ": {}", self.message)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<Error> for Retcode {
+    fn from(error: Error) -> Self {
+        error.retcode
+    }
+}
+
+impl ScipPtr {
+    /// Returns an `InvalidCall` error if the SCIP instance is not in one of the allowed stages, instead of letting
+    /// SCIP abort.
+    pub(crate) fn check_stage(
+        &self,
+        operation: &'static str,
+        name: Option<&str>,
+        allowed: &[Stage],
+    ) -> Result<(), Error> {
+        let stage = self.stage();
+        if allowed.contains(&stage) {
+            Ok(())
+        } else {
+            Err(Error::new(Retcode::InvalidCall, operation, name, stage)
+                .with_message(&format!("{operation} cannot be called in stage {stage:?}")))
+        }
+    }
+
+    /// Runs a fallible operation on the SCIP instance, attaching its context and the error messages SCIP printed
+    /// during the operation to the error.
+    pub(crate) fn try_call<T>(
+        &self,
+        operation: &'static str,
+        name: Option<&str>,
+        call: impl FnOnce(&ScipPtr) -> Result<T, Retcode>,
+    ) -> Result<T, Error> {
+        // the errors are routed to the handler of this model, callbacks (weak pointers) keep the route of the
+        // model calling them
+        let (res, message) = if self.weak {
+            capture_errors(|| call(self))
+        } else {
+            let handler = self.message_handler.borrow().clone();
+            with_error_route(handler.as_ref(), || capture_errors(|| call(self)))
+        };
+        res.map_err(|retcode| {
+            Error::new(retcode, operation, name, self.stage()).with_message(&message)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Model;
+
+    #[test]
+    fn display() {
+        let error = Error::new(Retcode::InvalidCall, "add_cons", Some("c1"), Stage::Solved);
+        assert_eq!(
+            error.to_string(),
+            "add_cons `c1` failed with retcode InvalidCall in stage Solved"
+        );
+        assert_eq!(Retcode::from(error), Retcode::InvalidCall);
+    }
+
+    #[test]
+    fn captures_scip_message() {
+        let err = Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .read_prob("data/test/does_not_exist.lp")
+            .unwrap_err();
+        assert_eq!(err.retcode(), Retcode::NoFile);
+        assert_eq!(err.operation(), "read_prob");
+        assert_eq!(err.name(), Some("data/test/does_not_exist.lp"));
+        assert!(err.message().contains("does_not_exist.lp"));
+        assert!(err.to_string().contains(err.message()));
+
+        let err = Model::new()
+            .hide_output()
+            .set_real_param("limits/gap", -1.)
+            .unwrap_err();
+        assert_eq!(err.retcode(), Retcode::ParameterWrongVal);
+        assert_eq!(err.name(), Some("limits/gap"));
+        assert!(err.message().contains("limits/gap"));
+    }
+}
@@ -103,8 +103,14 @@ impl From<HeurResult> for SCIP_Result {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::prelude::heur;
-    use crate::{Model, ModelWithProblem, ProblemOrSolving};
+    use crate::prelude::{cons, heur, var};
+    use crate::{
+        Model, ModelWithProblem, ParamSetting, ProblemOrSolving, Retcode, Stage, Status,
+        WithSolutions,
+    };
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    use std::sync::{Arc, Mutex};
 
     struct NoSolutionFoundHeur;
 
@@ -254,4 +260,133 @@ mod tests {
         model.add(heur(h).name("found_sol_heur"));
         model.solve();
     }
+
+    struct StageCheckingHeur {
+        stages: Rc<RefCell<Vec<Stage>>>,
+    }
+
+    impl Heuristic for StageCheckingHeur {
+        fn execute(
+            &mut self,
+            mut model: Model<Solving>,
+            _timing: HeurTiming,
+            _node_inf: bool,
+        ) -> HeurResult {
+            let stage = model.stage();
+            self.stages.borrow_mut().push(stage);
+            if stage == Stage::Presolving {
+                let err = model.try_create_child().unwrap_err();
+                assert_eq!(err.retcode(), Retcode::InvalidCall);
+                assert_eq!(err.stage(), Stage::Presolving);
+            }
+            HeurResult::DidNotRun
+        }
+    }
+
+    #[test]
+    fn stage_in_heur() {
+        let mut model = Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .read_prob("data/test/simple.lp")
+            .unwrap();
+
+        let stages = Rc::new(RefCell::new(Vec::new()));
+        let h = StageCheckingHeur {
+            stages: stages.clone(),
+        };
+        model.add(
+            heur(h)
+                .name("stage_checking_heur")
+                .timing(HeurTiming::BEFORE_PRESOL | HeurTiming::BEFORE_NODE),
+        );
+        model.solve();
+
+        let stages = stages.borrow();
+        assert!(stages.contains(&Stage::Presolving));
+        assert!(stages.contains(&Stage::Solving));
+    }
+
+    struct SubScipHeur {
+        found: Arc<Mutex<bool>>,
+    }
+
+    impl Heuristic for SubScipHeur {
+        fn execute(
+            &mut self,
+            model: Model<Solving>,
+            _timing: HeurTiming,
+            _node_inf: bool,
+        ) -> HeurResult {
+            let (mut sub, var_map) = model.copy().unwrap();
+            assert_eq!(var_map.len(), model.n_vars());
+
+            // fix c to 1 in the sub-problem
+            let c = model
+                .vars()
+                .into_iter()
+                .find(|v| v.name().ends_with('c'))
+                .unwrap();
+            sub.add(cons().coef(&var_map[&c.index()], 1.0).eq(1.0));
+            let sub = sub.solve();
+            assert_eq!(sub.status(), Status::Optimal);
+            let sub_sol = sub.best_sol().unwrap();
+
+            let sol = model.create_sol();
+            for (var_id, sub_var) in &var_map {
+                sol.set_val(&model.var(*var_id).unwrap(), sub_sol.val(sub_var));
+            }
+            assert_eq!(model.add_sol(sol), Ok(()));
+            *self.found.lock().unwrap() = true;
+            HeurResult::FoundSol
+        }
+    }
+
+    #[test]
+    fn sub_scip() {
+        let mut model = Model::new()
+            .hide_output()
+            .set_presolving(ParamSetting::Off)
+            .include_default_plugins()
+            .create_prob("sub_scip")
+            .maximize();
+        let a = model.add(var().bin().obj(5.0).name("a"));
+        let b = model.add(var().bin().obj(4.0).name("b"));
+        let c = model.add(var().bin().obj(3.0).name("c"));
+        model.add(cons().coef(&a, 2.0).coef(&b, 3.0).coef(&c, 1.0).le(4.0));
+
+        let found = Arc::new(Mutex::new(false));
+        model.add(
+            heur(SubScipHeur {
+                found: found.clone(),
+            })
+            .name("sub_scip_heur")
+            .timing(HeurTiming::BEFORE_NODE)
+            .maxdepth(0)
+            .usessubscip(true),
+        );
+        let solved = model.solve();
+        assert!(*found.lock().unwrap());
+        assert_eq!(solved.obj_val(), 8.0);
+    }
+
+    #[test]
+    fn copy_orig() {
+        let model = Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .read_prob("data/test/simple.lp")
+            .unwrap();
+        let (copy, var_map) = model.copy_orig().unwrap();
+        assert_eq!(copy.n_vars(), 2);
+        assert_eq!(copy.n_conss(), 2);
+        for var in model.vars() {
+            assert_eq!(var_map[&var.index()].name(), var.name());
+        }
+        drop(model);
+
+        let solved = copy.solve();
+        assert_eq!(solved.status(), Status::Optimal);
+        assert_eq!(solved.obj_val(), 200.0);
+    }
 }
@@ -0,0 +1,117 @@
+use crate::scip::ScipPtr;
+use crate::{ffi, Constraint, Retcode, Variable};
+use scip_sys::{SCIP_Cons, SCIP_Var};
+
+/// The lower or upper bound of a variable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum BoundType {
+    /// The lower bound.
+    Lower,
+    /// The upper bound.
+    Upper,
+}
+
+/// An irreducible infeasible subset (IIS) of an infeasible model, obtained through
+/// [`Model::compute_iis`](crate::Model::compute_iis).
+///
+/// The constraints and variable bounds of an IIS are infeasible together, while removing any one of them makes the
+/// problem feasible. The types of the variables, e.g. integrality and the domain of binary variables, are always
+/// kept.
+#[derive(Debug, Clone)]
+pub struct Iis {
+    /// The constraints of the IIS.
+    pub conss: Vec<Constraint>,
+    /// The variable bounds of the IIS.
+    pub bounds: Vec<(Variable, BoundType)>,
+}
+
+/// The constraints and variable bounds of an IIS, as found by `deletion_filter`.
+pub(crate) type RawIis = (Vec<*mut SCIP_Cons>, Vec<(*mut SCIP_Var, BoundType)>);
+
+/// Finds an IIS of the original problem of an infeasible SCIP instance, by removing one constraint or bound at a time
+/// and keeping it only if the remaining problem is not infeasible anymore.
+///
+/// Problems that are not proven infeasible after removing an element, e.g. because a limit was reached, keep the
+/// element, so the result is always infeasible but might not be irreducible in that case.
+pub(crate) fn deletion_filter(scip: &ScipPtr) -> Result<RawIis, Retcode> {
+    let mut removed_conss = Vec::new();
+    let mut iis_conss = Vec::new();
+    for cons in scip.orig_conss() {
+        removed_conss.push(cons);
+        if !scip.is_infeasible_without(&removed_conss, &[])? {
+            removed_conss.pop();
+            iis_conss.push(cons);
+        }
+    }
+
+    let infinity = unsafe { ffi::SCIPinfinity(scip.raw) };
+    let mut relaxed_bounds = Vec::new();
+    let mut iis_bounds = Vec::new();
+    for var in scip.orig_vars() {
+        if unsafe { ffi::SCIPvarGetType(var) } == ffi::SCIP_Vartype_SCIP_VARTYPE_BINARY {
+            continue;
+        }
+        let lb = unsafe { ffi::SCIPvarGetLbOriginal(var) };
+        let ub = unsafe { ffi::SCIPvarGetUbOriginal(var) };
+        for (bound, is_finite) in [
+            (BoundType::Lower, lb > -infinity),
+            (BoundType::Upper, ub < infinity),
+        ] {
+            if !is_finite {
+                continue;
+            }
+            relaxed_bounds.push((var, bound));
+            if !scip.is_infeasible_without(&removed_conss, &relaxed_bounds)? {
+                relaxed_bounds.pop();
+                iis_bounds.push((var, bound));
+            }
+        }
+    }
+    Ok((iis_conss, iis_bounds))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn iis() {
+        let mut model = Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .create_prob("iis");
+        let x = model.add(var().int(0..=10).obj(1.0).name("x"));
+        let y = model.add(var().int(0..=10).obj(1.0).name("y"));
+        let z = model.add(var().bin().obj(1.0).name("z"));
+        model.add(cons().name("c1").coef(&x, 1.0).coef(&y, 1.0).ge(15.0));
+        model.add(cons().name("c2").coef(&x, 1.0).le(4.0));
+        model.add(cons().name("c3").coef(&z, 1.0).coef(&y, 1.0).le(11.0));
+        model.add(cons().name("c4").coef(&x, 1.0).coef(&z, 1.0).ge(1.0));
+
+        let solved = model.solve();
+        assert_eq!(solved.status(), Status::Infeasible);
+        let iis = solved.compute_iis().unwrap();
+
+        // x + y >= 15 clashes with x <= 4 and y <= 10
+        let mut names: Vec<String> = iis.conss.iter().map(|c| c.name()).collect();
+        names.sort();
+        assert_eq!(names, vec!["c1", "c2"]);
+        assert_eq!(iis.bounds.len(), 1);
+        assert_eq!(iis.bounds[0].0.name(), "y");
+        assert_eq!(iis.bounds[0].1, BoundType::Upper);
+    }
+
+    #[test]
+    fn feasible_model() {
+        let model = Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .read_prob("data/test/simple.lp")
+            .unwrap()
+            .solve();
+        let err = model.compute_iis().unwrap_err();
+        assert_eq!(err.retcode(), Retcode::InvalidCall);
+    }
+}
@@ -0,0 +1,105 @@
+use crate::{Event, EventMask, Eventhdlr, Model, SCIPEventhdlr, Solving};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// A thread-safe handle to interrupt the solving process of a `Model`, obtained through
+/// [`Model::interrupt_handle`](crate::Model::interrupt_handle).
+///
+/// Calling [`interrupt`](InterruptHandle::interrupt) (from any thread) stops the running (or next) solve at the next
+/// presolving round, node or LP solve, the solved model then has the status `Status::UserInterrupt`.
+/// The solving process can be resumed by calling `solve` again on the solved model.
+///
+/// Note that SCIP also stops on Ctrl-C by itself, this can be turned off through the `misc/catchctrlc` parameter.
+#[derive(Debug, Clone, Default)]
+pub struct InterruptHandle {
+    requested: Arc<AtomicBool>,
+}
+
+impl InterruptHandle {
+    /// Requests the solving process to be interrupted.
+    pub fn interrupt(&self) {
+        self.requested.store(true, Ordering::SeqCst);
+    }
+
+    /// Returns whether an interrupt was requested and has not been handled by the solver yet.
+    pub fn is_pending(&self) -> bool {
+        self.requested.load(Ordering::SeqCst)
+    }
+}
+
+/// Event handler that calls `SCIPinterruptSolve` on the solving thread once an interrupt was requested.
+pub(crate) struct InterruptEventhdlr {
+    requested: Arc<AtomicBool>,
+}
+
+impl InterruptEventhdlr {
+    pub(crate) const NAME: &'static str = "russcip_interrupt";
+
+    pub(crate) fn new(handle: &InterruptHandle) -> Self {
+        InterruptEventhdlr {
+            requested: handle.requested.clone(),
+        }
+    }
+}
+
+impl Eventhdlr for InterruptEventhdlr {
+    fn get_type(&self) -> EventMask {
+        EventMask::PRESOLVE_ROUND | EventMask::NODE_FOCUSED | EventMask::LP_EVENT
+    }
+
+    fn execute(&mut self, model: Model<Solving>, _eventhdlr: SCIPEventhdlr, _event: Event) {
+        // the request is consumed, so that calling solve again resumes the solving process
+        if self.requested.swap(false, Ordering::SeqCst) {
+            // panicking would unwind through SCIP's callback, so failures are only reported as a warning
+            if let Err(err) = model
+                .scip
+                .try_call("interrupt_solve", None, |scip| scip.interrupt_solve())
+            {
+                model.scip.warning_message(&err.to_string());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn interrupt_and_resume() {
+        let mut model = Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .read_prob("data/test/gen-ip054.mps")
+            .unwrap();
+
+        let handle = model.interrupt_handle();
+        let remote = handle.clone();
+        std::thread::spawn(move || remote.interrupt())
+            .join()
+            .unwrap();
+        assert!(handle.is_pending());
+
+        let solved = model.solve();
+        assert_eq!(solved.status(), Status::UserInterrupt);
+        assert!(!handle.is_pending());
+
+        let solved = solved.solve();
+        assert_eq!(solved.status(), Status::Optimal);
+    }
+
+    #[test]
+    fn handle_is_shared() {
+        let mut model = Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .read_prob("data/test/gen-ip054.mps")
+            .unwrap();
+
+        let first = model.interrupt_handle();
+        let second = model.interrupt_handle();
+        first.interrupt();
+        assert!(second.is_pending());
+        assert_eq!(model.solve().status(), Status::UserInterrupt);
+    }
+}
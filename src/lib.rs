@@ -36,18 +36,42 @@ pub use constraint::*;
 pub mod model;
 pub use model::*;
 
+/// Contains the `MessageHandler` trait used to route the output of SCIP.
+pub mod message;
+pub use message::*;
+
+/// Contains the `InterruptHandle` struct, used to interrupt the solving process from another thread.
+pub mod interrupt;
+pub use interrupt::*;
+
+/// Contains the `SolveHandle` struct, used to solve a model on a background thread.
+pub mod solve_async;
+pub use solve_async::*;
+
 /// Contains the `Pricer` trait used to define custom variable pricing strategies.
 pub mod pricer;
 pub use pricer::*;
 
+/// Contains the `Error` struct, which is returned by the fallible methods of `Model`.
+pub mod error;
+pub use error::*;
+
 /// Contains the `Retcode` enum, which represents the return codes of SCIP functions.
 pub mod retcode;
 pub use retcode::*;
 
-/// Contains the `Solution` struct, which represents a solution to an optimization problem.
+/// Contains the `Solution` struct, which represents a solution to an optimization problem, and its owned snapshot `SolutionValues`.
 pub mod solution;
 pub use solution::*;
 
+/// Contains the `SolveSummary` and `Statistics` structs, which hold the statistics of a solving run.
+pub mod stats;
+pub use stats::*;
+
+/// Contains the `Stage` enum, which represents the stage of a SCIP instance.
+pub mod stage;
+pub use stage::*;
+
 /// Contains the `Status` enum, which represents the status of an optimization problem.
 pub mod status;
 pub use status::*;
@@ -60,6 +84,10 @@ pub use variable::*;
 pub mod node;
 pub use node::*;
 
+/// Contains the `DisplayColumn` trait used to define custom columns of the progress table.
+pub mod display;
+pub use display::*;
+
 /// Contains the `EventHdlr` trait used to define custom event handlers.
 pub mod eventhdlr;
 pub use eventhdlr::*;
@@ -68,6 +96,14 @@ pub use eventhdlr::*;
 pub mod heuristic;
 pub use heuristic::*;
 
+/// Contains the `DiveGuard` struct, which gives access to LP diving during the solving process.
+pub mod dive;
+pub use dive::*;
+
+/// Contains the `ProbingGuard` struct, which gives access to SCIP's probing mode during the solving process.
+pub mod probing;
+pub use probing::*;
+
 /// Contains the `Separator` trait used to define custom separation routines.
 pub mod separator;
 pub use separator::*;
@@ -82,9 +118,29 @@ pub mod col;
 pub use col::*;
 
 mod param;
+
+/// Contains a typed catalogue of SCIP parameters, e.g. `params::limits::Time`, for use with `Model::set`.
+pub mod params;
+
 /// Contains the `Row` struct, which represents a row in an LP relaxation.
 pub mod row;
 
+/// Contains the `LpSolStat` and `BasisIndex` enums, which describe the state of the LP relaxation.
+pub mod lp;
+pub use lp::*;
+
+/// Contains the `Lpi` struct, a standalone LP solver through SCIP's LP interface.
+pub mod lpi;
+pub use lpi::*;
+
+/// Contains the `Iis` struct, an irreducible infeasible subset of an infeasible model.
+pub mod iis;
+pub use iis::*;
+
+/// Contains the `BendersCut` trait used to define custom cuts of a Benders' decomposition.
+pub mod benders;
+pub use benders::*;
+
 /// Contains methods for creating scip objects in an ergonomic way.
 pub mod builder;
 
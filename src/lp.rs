@@ -0,0 +1,139 @@
+use crate::ffi;
+
+/// The solution status of the LP relaxation.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum LpSolStat {
+    /// The LP was not solved yet.
+    NotSolved,
+    /// The LP was solved to optimality.
+    Optimal,
+    /// The LP is primal infeasible.
+    Infeasible,
+    /// The LP has a primal unbounded ray.
+    UnboundedRay,
+    /// The objective limit was reached.
+    ObjLimit,
+    /// The iteration limit was reached.
+    IterLimit,
+    /// The time limit was reached.
+    TimeLimit,
+    /// An error occurred while solving the LP.
+    Error,
+}
+
+impl From<ffi::SCIP_LPSOLSTAT> for LpSolStat {
+    fn from(status: ffi::SCIP_LPSOLSTAT) -> Self {
+        match status {
+            ffi::SCIP_LPSolStat_SCIP_LPSOLSTAT_NOTSOLVED => LpSolStat::NotSolved,
+            ffi::SCIP_LPSolStat_SCIP_LPSOLSTAT_OPTIMAL => LpSolStat::Optimal,
+            ffi::SCIP_LPSolStat_SCIP_LPSOLSTAT_INFEASIBLE => LpSolStat::Infeasible,
+            ffi::SCIP_LPSolStat_SCIP_LPSOLSTAT_UNBOUNDEDRAY => LpSolStat::UnboundedRay,
+            ffi::SCIP_LPSolStat_SCIP_LPSOLSTAT_OBJLIMIT => LpSolStat::ObjLimit,
+            ffi::SCIP_LPSolStat_SCIP_LPSOLSTAT_ITERLIMIT => LpSolStat::IterLimit,
+            ffi::SCIP_LPSolStat_SCIP_LPSOLSTAT_TIMELIMIT => LpSolStat::TimeLimit,
+            ffi::SCIP_LPSolStat_SCIP_LPSOLSTAT_ERROR => LpSolStat::Error,
+            _ => panic!("Unknown LP solution status {status:?}"),
+        }
+    }
+}
+
+/// The variable that is basic in a row of the LP basis, see `Model::lp_basis_ind`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum BasisIndex {
+    /// The column at the given LP position is basic.
+    Col(usize),
+    /// The slack variable of the row at the given LP position is basic.
+    Row(usize),
+}
+
+impl From<std::os::raw::c_int> for BasisIndex {
+    fn from(ind: std::os::raw::c_int) -> Self {
+        if ind >= 0 {
+            BasisIndex::Col(ind as usize)
+        } else {
+            BasisIndex::Row((-1 - ind) as usize)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use crate::{BasisStatus, SCIPSeparator, SeparationResult, Separator, Solving};
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn basis_index() {
+        assert_eq!(BasisIndex::from(3), BasisIndex::Col(3));
+        assert_eq!(BasisIndex::from(-1), BasisIndex::Row(0));
+        assert_eq!(BasisIndex::from(-4), BasisIndex::Row(3));
+    }
+
+    struct TableauSeparator {
+        n_calls: Arc<Mutex<usize>>,
+    }
+
+    impl Separator for TableauSeparator {
+        fn execute_lp(&mut self, model: Model<Solving>, _sepa: SCIPSeparator) -> SeparationResult {
+            if model.lp_sol_stat() != LpSolStat::Optimal || !model.is_lp_sol_basic() {
+                return SeparationResult::DidNotRun;
+            }
+
+            let rows = model.lp_rows();
+            let cols = model.lp_cols();
+            assert!(!rows.is_empty());
+            assert!(cols
+                .iter()
+                .enumerate()
+                .all(|(i, col)| col.lp_pos() == Some(i)));
+
+            assert!(model.lp_obj_val().is_finite());
+            for col in cols
+                .iter()
+                .filter(|col| col.basis_status() == BasisStatus::Basic)
+            {
+                let redcost = col.redcost().unwrap();
+                assert!(redcost.abs() < 1e-6);
+                assert_eq!(col.var().redcost(), Some(redcost));
+            }
+
+            let basis = model.lp_basis_ind().unwrap();
+            assert_eq!(basis.len(), rows.len());
+            for (r, ind) in basis.iter().enumerate() {
+                assert_eq!(model.lp_binv_row(r).unwrap().len(), rows.len());
+                if let BasisIndex::Col(c) = ind {
+                    // the tableau row of a basic column is a unit vector
+                    let binva = model.lp_binva_row(r).unwrap();
+                    assert_eq!(binva.len(), cols.len());
+                    assert!((binva[*c] - 1.0).abs() < 1e-6);
+                }
+            }
+
+            *self.n_calls.lock().unwrap() += 1;
+            SeparationResult::DidNotFind
+        }
+    }
+
+    #[test]
+    fn tableau() {
+        let mut model = Model::new()
+            .hide_output()
+            .set_longint_param("limits/nodes", 1)
+            .unwrap()
+            .include_default_plugins()
+            .read_prob("data/test/gen-ip054.mps")
+            .unwrap();
+
+        let n_calls = Arc::new(Mutex::new(0));
+        model.add(
+            sepa(TableauSeparator {
+                n_calls: n_calls.clone(),
+            })
+            .name("tableau_separator"),
+        );
+        model.solve();
+        assert!(*n_calls.lock().unwrap() > 0);
+    }
+}
@@ -0,0 +1,766 @@
+use crate::message::capture_errors;
+use crate::{ffi, scip_call_panic, BasisIndex, BasisStatus, Error, ObjSense, Retcode, Stage};
+use std::ffi::{c_int, CString};
+use std::rc::Rc;
+
+/// A standalone LP, solved by the LP solver SCIP was built with through SCIP's LP interface (LPI).
+///
+/// The LP is independent of any `Model`, columns and rows are addressed by their position. A basis obtained through
+/// [`Lpi::state`] can later be loaded again through [`Lpi::set_state`] to warm start the simplex.
+///
+/// Failures are returned as an [`Error`] in the `Init` stage, as the LP does not belong to a SCIP instance.
+#[derive(Debug)]
+pub struct Lpi {
+    inner: Rc<LpiPtr>,
+}
+
+/// Owner of the raw LPI and the block memory its states are allocated in, shared with the states so the LPI outlives
+/// them.
+#[derive(Debug)]
+struct LpiPtr {
+    raw: *mut ffi::SCIP_LPI,
+    blkmem: *mut ffi::BMS_BLKMEM,
+}
+
+impl Drop for LpiPtr {
+    fn drop(&mut self) {
+        scip_call_panic!(ffi::SCIPlpiFree(&mut self.raw));
+        unsafe {
+            ffi::BMSdestroyBlockMemory_call(&mut self.blkmem, c"lpi.rs".as_ptr(), line!() as c_int)
+        };
+    }
+}
+
+/// The warm start information (e.g. the basis) of an `Lpi`, obtained through [`Lpi::state`].
+#[derive(Debug)]
+pub struct LpiState {
+    raw: *mut ffi::SCIP_LPISTATE,
+    lpi: Rc<LpiPtr>,
+}
+
+impl Drop for LpiState {
+    fn drop(&mut self) {
+        scip_call_panic!(ffi::SCIPlpiFreeState(
+            self.lpi.raw,
+            self.lpi.blkmem,
+            &mut self.raw
+        ));
+    }
+}
+
+/// The solution of an `Lpi`, see [`Lpi::sol`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct LpiSolution {
+    /// The objective value.
+    pub obj_val: f64,
+    /// The primal values of the columns.
+    pub primal: Vec<f64>,
+    /// The dual values of the rows.
+    pub dual: Vec<f64>,
+    /// The activities of the rows.
+    pub activity: Vec<f64>,
+    /// The reduced costs of the columns.
+    pub redcost: Vec<f64>,
+}
+
+impl Lpi {
+    /// Creates an empty LP with the given name and objective sense.
+    pub fn new(name: &str, sense: ObjSense) -> Result<Self, Error> {
+        let c_name = CString::new(name)
+            .map_err(|_| Error::new(Retcode::InvalidData, "lpi_new", Some(name), Stage::Init))?;
+        let objsen = match sense {
+            ObjSense::Minimize => ffi::SCIP_ObjSen_SCIP_OBJSEN_MINIMIZE,
+            ObjSense::Maximize => ffi::SCIP_ObjSen_SCIP_OBJSEN_MAXIMIZE,
+        };
+        let blkmem =
+            unsafe { ffi::BMScreateBlockMemory_call(1, 10, c"lpi.rs".as_ptr(), line!() as c_int) };
+        if blkmem.is_null() {
+            return Err(Error::new(
+                Retcode::NoMemory,
+                "lpi_new",
+                Some(name),
+                Stage::Init,
+            ));
+        }
+        let mut raw = std::ptr::null_mut();
+        if let Err(error) = lpi_call("lpi_new", || unsafe {
+            ffi::SCIPlpiCreate(&mut raw, std::ptr::null_mut(), c_name.as_ptr(), objsen)
+        }) {
+            let mut blkmem = blkmem;
+            unsafe {
+                ffi::BMSdestroyBlockMemory_call(&mut blkmem, c"lpi.rs".as_ptr(), line!() as c_int)
+            };
+            return Err(error);
+        }
+        Ok(Lpi {
+            inner: Rc::new(LpiPtr { raw, blkmem }),
+        })
+    }
+
+    fn raw(&self) -> *mut ffi::SCIP_LPI {
+        self.inner.raw
+    }
+
+    /// Returns the name and version of the LP solver.
+    pub fn solver_name() -> String {
+        let name = unsafe { std::ffi::CStr::from_ptr(ffi::SCIPlpiGetSolverName()) };
+        name.to_str().unwrap().to_string()
+    }
+
+    /// Returns the value the LP solver treats as infinity.
+    pub fn infinity(&self) -> f64 {
+        unsafe { ffi::SCIPlpiInfinity(self.raw()) }
+    }
+
+    /// Returns the number of columns of the LP.
+    pub fn n_cols(&self) -> Result<usize, Error> {
+        let mut n_cols = 0;
+        lpi_call("lpi_n_cols", || unsafe {
+            ffi::SCIPlpiGetNCols(self.raw(), &mut n_cols)
+        })?;
+        Ok(n_cols as usize)
+    }
+
+    /// Returns the number of rows of the LP.
+    pub fn n_rows(&self) -> Result<usize, Error> {
+        let mut n_rows = 0;
+        lpi_call("lpi_n_rows", || unsafe {
+            ffi::SCIPlpiGetNRows(self.raw(), &mut n_rows)
+        })?;
+        Ok(n_rows as usize)
+    }
+
+    /// Adds a column to the LP and returns its position.
+    ///
+    /// # Arguments
+    ///
+    /// * `obj` - The objective coefficient of the column.
+    /// * `lb` - The lower bound of the column.
+    /// * `ub` - The upper bound of the column.
+    /// * `coefs` - The coefficients of the column as pairs of row position and value, the rows must already exist.
+    ///
+    /// # Returns
+    ///
+    /// An `Error` with `Retcode::InvalidData` if a row does not exist.
+    pub fn add_col(
+        &mut self,
+        obj: f64,
+        lb: f64,
+        ub: f64,
+        coefs: &[(usize, f64)],
+    ) -> Result<usize, Error> {
+        let pos = self.n_cols()?;
+        let (ind, val) = split_coefs("lpi_add_col", coefs, self.n_rows()?, "row")?;
+        let beg = [0];
+        lpi_call("lpi_add_col", || unsafe {
+            ffi::SCIPlpiAddCols(
+                self.raw(),
+                1,
+                &obj,
+                &lb,
+                &ub,
+                std::ptr::null_mut(),
+                ind.len() as c_int,
+                beg.as_ptr(),
+                ind.as_ptr(),
+                val.as_ptr(),
+            )
+        })?;
+        Ok(pos)
+    }
+
+    /// Adds a row `lhs <= a^T x <= rhs` to the LP and returns its position.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side of the row.
+    /// * `rhs` - The right hand side of the row.
+    /// * `coefs` - The coefficients of the row as pairs of column position and value, the columns must already exist.
+    ///
+    /// # Returns
+    ///
+    /// An `Error` with `Retcode::InvalidData` if a column does not exist.
+    pub fn add_row(&mut self, lhs: f64, rhs: f64, coefs: &[(usize, f64)]) -> Result<usize, Error> {
+        let pos = self.n_rows()?;
+        let (ind, val) = split_coefs("lpi_add_row", coefs, self.n_cols()?, "column")?;
+        let beg = [0];
+        lpi_call("lpi_add_row", || unsafe {
+            ffi::SCIPlpiAddRows(
+                self.raw(),
+                1,
+                &lhs,
+                &rhs,
+                std::ptr::null_mut(),
+                ind.len() as c_int,
+                beg.as_ptr(),
+                ind.as_ptr(),
+                val.as_ptr(),
+            )
+        })?;
+        Ok(pos)
+    }
+
+    /// Changes the bounds of the column at the given position.
+    pub fn chg_bounds(&mut self, col: usize, lb: f64, ub: f64) -> Result<(), Error> {
+        let ind = self.col_index("lpi_chg_bounds", col)?;
+        lpi_call("lpi_chg_bounds", || unsafe {
+            ffi::SCIPlpiChgBounds(self.raw(), 1, &ind, &lb, &ub)
+        })
+    }
+
+    /// Changes the objective coefficient of the column at the given position.
+    pub fn chg_obj(&mut self, col: usize, obj: f64) -> Result<(), Error> {
+        let ind = self.col_index("lpi_chg_obj", col)?;
+        lpi_call("lpi_chg_obj", || unsafe {
+            ffi::SCIPlpiChgObj(self.raw(), 1, &ind, &obj)
+        })
+    }
+
+    /// Changes the sides of the row at the given position.
+    pub fn chg_sides(&mut self, row: usize, lhs: f64, rhs: f64) -> Result<(), Error> {
+        let ind = self.row_index("lpi_chg_sides", row)?;
+        lpi_call("lpi_chg_sides", || unsafe {
+            ffi::SCIPlpiChgSides(self.raw(), 1, &ind, &lhs, &rhs)
+        })
+    }
+
+    /// Solves the LP with the primal simplex, starting from the current basis if there is one.
+    pub fn solve_primal(&mut self) -> Result<(), Error> {
+        lpi_call("lpi_solve_primal", || unsafe {
+            ffi::SCIPlpiSolvePrimal(self.raw())
+        })
+    }
+
+    /// Solves the LP with the dual simplex, starting from the current basis if there is one.
+    pub fn solve_dual(&mut self) -> Result<(), Error> {
+        lpi_call("lpi_solve_dual", || unsafe {
+            ffi::SCIPlpiSolveDual(self.raw())
+        })
+    }
+
+    /// Returns whether the LP was solved to optimality.
+    pub fn is_optimal(&self) -> bool {
+        (unsafe { ffi::SCIPlpiIsOptimal(self.raw()) }) != 0
+    }
+
+    /// Returns whether the LP was proven to be primal infeasible.
+    pub fn is_primal_infeasible(&self) -> bool {
+        (unsafe { ffi::SCIPlpiIsPrimalInfeasible(self.raw()) }) != 0
+    }
+
+    /// Returns whether the LP was proven to be primal unbounded.
+    pub fn is_primal_unbounded(&self) -> bool {
+        (unsafe { ffi::SCIPlpiIsPrimalUnbounded(self.raw()) }) != 0
+    }
+
+    /// Returns the number of simplex iterations of the last solve.
+    pub fn iterations(&self) -> Result<usize, Error> {
+        let mut iterations = 0;
+        lpi_call("lpi_iterations", || unsafe {
+            ffi::SCIPlpiGetIterations(self.raw(), &mut iterations)
+        })?;
+        Ok(iterations as usize)
+    }
+
+    /// Returns the objective value of the last solve.
+    pub fn obj_val(&self) -> Result<f64, Error> {
+        let mut obj_val = 0.0;
+        lpi_call("lpi_obj_val", || unsafe {
+            ffi::SCIPlpiGetObjval(self.raw(), &mut obj_val)
+        })?;
+        Ok(obj_val)
+    }
+
+    /// Returns the primal and dual solution of the last solve.
+    pub fn sol(&self) -> Result<LpiSolution, Error> {
+        let n_cols = self.n_cols()?;
+        let n_rows = self.n_rows()?;
+        let mut sol = LpiSolution {
+            obj_val: 0.0,
+            primal: vec![0.0; n_cols],
+            dual: vec![0.0; n_rows],
+            activity: vec![0.0; n_rows],
+            redcost: vec![0.0; n_cols],
+        };
+        lpi_call("lpi_sol", || unsafe {
+            ffi::SCIPlpiGetSol(
+                self.raw(),
+                &mut sol.obj_val,
+                sol.primal.as_mut_ptr(),
+                sol.dual.as_mut_ptr(),
+                sol.activity.as_mut_ptr(),
+                sol.redcost.as_mut_ptr(),
+            )
+        })?;
+        Ok(sol)
+    }
+
+    /// Returns the basis status of the columns and of the rows of the current basis.
+    pub fn basis(&self) -> Result<(Vec<BasisStatus>, Vec<BasisStatus>), Error> {
+        let mut cstat = vec![0; self.n_cols()?];
+        let mut rstat = vec![0; self.n_rows()?];
+        lpi_call("lpi_basis", || unsafe {
+            ffi::SCIPlpiGetBase(self.raw(), cstat.as_mut_ptr(), rstat.as_mut_ptr())
+        })?;
+        let to_status = |stat: c_int| BasisStatus::from(stat as ffi::SCIP_BASESTAT);
+        Ok((
+            cstat.into_iter().map(to_status).collect(),
+            rstat.into_iter().map(to_status).collect(),
+        ))
+    }
+
+    /// Sets the basis status of all columns and rows.
+    ///
+    /// Returns an `Error` with `Retcode::InvalidData` if the number of statuses does not match the number of columns
+    /// or rows.
+    pub fn set_basis(
+        &mut self,
+        col_stats: &[BasisStatus],
+        row_stats: &[BasisStatus],
+    ) -> Result<(), Error> {
+        if col_stats.len() != self.n_cols()? || row_stats.len() != self.n_rows()? {
+            return Err(lpi_error(
+                Retcode::InvalidData,
+                "lpi_set_basis",
+                "the number of statuses does not match the number of columns and rows",
+            ));
+        }
+        let to_stat = |status: &BasisStatus| ffi::SCIP_BASESTAT::from(*status) as c_int;
+        let cstat: Vec<c_int> = col_stats.iter().map(to_stat).collect();
+        let rstat: Vec<c_int> = row_stats.iter().map(to_stat).collect();
+        lpi_call("lpi_set_basis", || unsafe {
+            ffi::SCIPlpiSetBase(self.raw(), cstat.as_ptr(), rstat.as_ptr())
+        })
+    }
+
+    /// Stores the current warm start information, e.g. after a solve.
+    pub fn state(&self) -> Result<LpiState, Error> {
+        let mut raw = std::ptr::null_mut();
+        lpi_call("lpi_state", || unsafe {
+            ffi::SCIPlpiGetState(self.raw(), self.inner.blkmem, &mut raw)
+        })?;
+        Ok(LpiState {
+            raw,
+            lpi: self.inner.clone(),
+        })
+    }
+
+    /// Loads warm start information stored before through [`Lpi::state`], columns and rows added since are set to
+    /// their lower bounds and basic, respectively.
+    ///
+    /// Returns an `Error` with `Retcode::InvalidData` if the state belongs to another `Lpi`.
+    pub fn set_state(&mut self, state: &LpiState) -> Result<(), Error> {
+        if !Rc::ptr_eq(&self.inner, &state.lpi) {
+            return Err(lpi_error(
+                Retcode::InvalidData,
+                "lpi_set_state",
+                "the state belongs to another LP",
+            ));
+        }
+        lpi_call("lpi_set_state", || unsafe {
+            ffi::SCIPlpiSetState(self.raw(), self.inner.blkmem, state.raw)
+        })
+    }
+
+    /// Returns, for each row of the basis, the column or row whose (slack) variable is basic in it.
+    pub fn basis_ind(&self) -> Result<Vec<BasisIndex>, Error> {
+        let mut basis_ind = vec![0; self.n_rows()?];
+        lpi_call("lpi_basis_ind", || unsafe {
+            ffi::SCIPlpiGetBasisInd(self.raw(), basis_ind.as_mut_ptr())
+        })?;
+        Ok(basis_ind.into_iter().map(BasisIndex::from).collect())
+    }
+
+    /// Returns the given row of the inverse of the basis matrix, as a dense vector over the rows.
+    pub fn binv_row(&self, row: usize) -> Result<Vec<f64>, Error> {
+        let ind = self.row_index("lpi_binv_row", row)?;
+        let mut coefs = vec![0.0; self.n_rows()?];
+        lpi_call("lpi_binv_row", || unsafe {
+            ffi::SCIPlpiGetBInvRow(
+                self.raw(),
+                ind,
+                coefs.as_mut_ptr(),
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+            )
+        })?;
+        Ok(coefs)
+    }
+
+    /// Returns the given column of the inverse of the basis matrix, as a dense vector over the rows of the basis.
+    pub fn binv_col(&self, col: usize) -> Result<Vec<f64>, Error> {
+        // the inverse of the basis matrix is square, with one column per row of the LP
+        let ind = self.row_index("lpi_binv_col", col)?;
+        let mut coefs = vec![0.0; self.n_rows()?];
+        lpi_call("lpi_binv_col", || unsafe {
+            ffi::SCIPlpiGetBInvCol(
+                self.raw(),
+                ind,
+                coefs.as_mut_ptr(),
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+            )
+        })?;
+        Ok(coefs)
+    }
+
+    /// Returns the given row of the simplex tableau, i.e., the product of the given row of the inverse of the basis
+    /// matrix with the constraint matrix, as a dense vector over the columns.
+    pub fn binva_row(&self, row: usize) -> Result<Vec<f64>, Error> {
+        let ind = self.row_index("lpi_binva_row", row)?;
+        let mut coefs = vec![0.0; self.n_cols()?];
+        lpi_call("lpi_binva_row", || unsafe {
+            ffi::SCIPlpiGetBInvARow(
+                self.raw(),
+                ind,
+                std::ptr::null(),
+                coefs.as_mut_ptr(),
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+            )
+        })?;
+        Ok(coefs)
+    }
+
+    /// Returns the range in which the objective coefficient of the given column can vary while the current (optimal)
+    /// basis stays optimal. Unbounded ends are given as [`infinity`](Lpi::infinity).
+    pub fn obj_ranging(&self, col: usize) -> Result<(f64, f64), Error> {
+        let ind = self.col_index("lpi_obj_ranging", col)?;
+        let inf = self.infinity();
+        let maximize = self.is_maximize()?;
+        let mut obj = 0.0;
+        lpi_call("lpi_obj_ranging", || unsafe {
+            ffi::SCIPlpiGetObj(self.raw(), ind, ind, &mut obj)
+        })?;
+        let sol = self.sol()?;
+        let (cstat, rstat) = self.basis()?;
+
+        // the change `delta` of the objective coefficient is feasible as long as the reduced costs of the nonbasic
+        // columns and the dual values of the nonbasic rows keep their signs
+        let mut delta = (-inf, inf);
+        let Some(r) = self
+            .basis_ind()?
+            .iter()
+            .position(|ind| *ind == BasisIndex::Col(col))
+        else {
+            // only the reduced cost of the column itself changes
+            let redcost = sol.redcost[col];
+            let (lb, ub) = self.bounds(col)?;
+            match dual_sign(cstat[col], maximize) {
+                _ if lb == ub => {}
+                Some(sign) if sign > 0.0 => delta.0 = -redcost,
+                Some(_) => delta.1 = -redcost,
+                None => delta = (-redcost, -redcost),
+            }
+            return Ok(shift_range(obj, delta, inf));
+        };
+
+        // the tableau row of the column gives the change of the reduced costs and dual values per unit of `delta`,
+        // the slack variable of a row is the negative row activity
+        let tableau_cols = self.binva_row(r)?;
+        let tableau_rows = self.binv_row(r)?;
+        for (c, status) in cstat.iter().enumerate() {
+            let (lb, ub) = self.bounds(c)?;
+            if *status != BasisStatus::Basic && lb != ub {
+                restrict(
+                    &mut delta,
+                    sol.redcost[c],
+                    tableau_cols[c],
+                    *status,
+                    maximize,
+                );
+            }
+        }
+        for (i, status) in rstat.iter().enumerate() {
+            let (lhs, rhs) = self.sides(i)?;
+            if *status != BasisStatus::Basic && lhs != rhs {
+                restrict(&mut delta, sol.dual[i], -tableau_rows[i], *status, maximize);
+            }
+        }
+        Ok(shift_range(obj, delta, inf))
+    }
+
+    /// Returns the range in which the active side of the given row can vary while the current (optimal) basis stays
+    /// primal feasible, so that the dual value of the row stays valid. For a row that is not active (whose slack
+    /// variable is basic), this is the range of its right hand side, or of its left hand side if the right hand side
+    /// is infinite. Unbounded ends are given as [`infinity`](Lpi::infinity).
+    pub fn rhs_ranging(&self, row: usize) -> Result<(f64, f64), Error> {
+        self.row_index("lpi_rhs_ranging", row)?;
+        let inf = self.infinity();
+        let sol = self.sol()?;
+        let (_, rstat) = self.basis()?;
+        let activity = sol.activity[row];
+
+        if rstat[row] == BasisStatus::Basic {
+            let (_, rhs) = self.sides(row)?;
+            return Ok(if rhs < inf {
+                (activity, inf)
+            } else {
+                (-inf, activity)
+            });
+        }
+
+        // moving the side by `delta` moves the basic variables along the corresponding column of the inverse basis
+        // matrix, the slack variable of a row is the negative row activity
+        let mut delta = (-inf, inf);
+        let binv_col = self.binv_col(row)?;
+        for (r, ind) in self.basis_ind()?.into_iter().enumerate() {
+            let (val, step, (lb, ub)) = match ind {
+                BasisIndex::Col(c) => (sol.primal[c], binv_col[r], self.bounds(c)?),
+                BasisIndex::Row(i) => (sol.activity[i], -binv_col[r], self.sides(i)?),
+            };
+            if step.abs() < RANGING_EPS {
+                continue;
+            }
+            for (bound, is_lb) in [(lb, true), (ub, false)] {
+                if bound.abs() >= inf {
+                    continue;
+                }
+                let limit = (bound - val) / step;
+                // the lower bound limits a decrease of the basic variable, the upper bound an increase
+                if is_lb == (step > 0.0) {
+                    delta.0 = delta.0.max(limit);
+                } else {
+                    delta.1 = delta.1.min(limit);
+                }
+            }
+        }
+        Ok(shift_range(activity, delta, inf))
+    }
+
+    /// Returns the position of an existing column as index for the LP interface, which only checks indices in debug
+    /// builds.
+    fn col_index(&self, operation: &'static str, col: usize) -> Result<c_int, Error> {
+        check_index(operation, col, self.n_cols()?, "column")
+    }
+
+    /// Returns the position of an existing row as index for the LP interface.
+    fn row_index(&self, operation: &'static str, row: usize) -> Result<c_int, Error> {
+        check_index(operation, row, self.n_rows()?, "row")
+    }
+
+    fn is_maximize(&self) -> Result<bool, Error> {
+        let mut objsen = ffi::SCIP_ObjSen_SCIP_OBJSEN_MINIMIZE;
+        lpi_call("lpi_objsen", || unsafe {
+            ffi::SCIPlpiGetObjsen(self.raw(), &mut objsen)
+        })?;
+        Ok(objsen == ffi::SCIP_ObjSen_SCIP_OBJSEN_MAXIMIZE)
+    }
+
+    fn bounds(&self, col: usize) -> Result<(f64, f64), Error> {
+        let ind = self.col_index("lpi_bounds", col)?;
+        let (mut lb, mut ub) = (0.0, 0.0);
+        lpi_call("lpi_bounds", || unsafe {
+            ffi::SCIPlpiGetBounds(self.raw(), ind, ind, &mut lb, &mut ub)
+        })?;
+        Ok((lb, ub))
+    }
+
+    fn sides(&self, row: usize) -> Result<(f64, f64), Error> {
+        let ind = self.row_index("lpi_sides", row)?;
+        let (mut lhs, mut rhs) = (0.0, 0.0);
+        lpi_call("lpi_sides", || unsafe {
+            ffi::SCIPlpiGetSides(self.raw(), ind, ind, &mut lhs, &mut rhs)
+        })?;
+        Ok((lhs, rhs))
+    }
+}
+
+/// Calls a function of the LP interface, returning an `Error` with the messages it printed if it fails.
+///
+/// The LP is independent of any SCIP instance, so errors are reported in the `Init` stage.
+fn lpi_call(
+    operation: &'static str,
+    call: impl FnOnce() -> ffi::SCIP_RETCODE,
+) -> Result<(), Error> {
+    let (retcode, message) = capture_errors(|| Retcode::from(call()));
+    if retcode != Retcode::Okay {
+        return Err(lpi_error(retcode, operation, &message));
+    }
+    Ok(())
+}
+
+fn lpi_error(retcode: Retcode, operation: &'static str, message: &str) -> Error {
+    Error::new(retcode, operation, None, Stage::Init).with_message(message)
+}
+
+/// Converts the position of a column or row to an index for the LP interface, returning an `InvalidData` error if
+/// it does not exist.
+fn check_index(
+    operation: &'static str,
+    index: usize,
+    len: usize,
+    kind: &str,
+) -> Result<c_int, Error> {
+    match c_int::try_from(index) {
+        Ok(ind) if index < len => Ok(ind),
+        _ => Err(lpi_error(
+            Retcode::InvalidData,
+            operation,
+            &format!("{kind} {index} does not exist, the LP has {len} {kind}s"),
+        )),
+    }
+}
+
+const RANGING_EPS: f64 = 1e-9;
+
+/// Returns the sign the reduced cost (or dual value) of a nonbasic variable must have for the basis to be optimal,
+/// `None` if it must be zero.
+fn dual_sign(status: BasisStatus, maximize: bool) -> Option<f64> {
+    match (status, maximize) {
+        (BasisStatus::Lower, false) | (BasisStatus::Upper, true) => Some(1.0),
+        (BasisStatus::Lower, true) | (BasisStatus::Upper, false) => Some(-1.0),
+        _ => None,
+    }
+}
+
+/// Restricts the range of `delta` such that the reduced cost `redcost - delta * step` keeps its required sign.
+fn restrict(delta: &mut (f64, f64), redcost: f64, step: f64, status: BasisStatus, maximize: bool) {
+    if step.abs() < RANGING_EPS {
+        return;
+    }
+    let limit = redcost / step;
+    match dual_sign(status, maximize) {
+        Some(sign) if sign * step > 0.0 => delta.1 = delta.1.min(limit),
+        Some(_) => delta.0 = delta.0.max(limit),
+        None => *delta = (delta.0.max(limit), delta.1.min(limit)),
+    }
+}
+
+fn shift_range(val: f64, delta: (f64, f64), inf: f64) -> (f64, f64) {
+    let lower = if delta.0 <= -inf { -inf } else { val + delta.0 };
+    let upper = if delta.1 >= inf { inf } else { val + delta.1 };
+    (lower, upper)
+}
+
+fn split_coefs(
+    operation: &'static str,
+    coefs: &[(usize, f64)],
+    len: usize,
+    kind: &str,
+) -> Result<(Vec<c_int>, Vec<f64>), Error> {
+    coefs
+        .iter()
+        .map(|&(i, v)| Ok((check_index(operation, i, len, kind)?, v)))
+        .collect::<Result<Vec<_>, Error>>()
+        .map(|coefs| coefs.into_iter().unzip())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// max 3x1 + 4x2 s.t. 2x1 + x2 <= 100, x1 + 2x2 <= 80, x >= 0
+    fn simple_lp() -> Lpi {
+        let mut lpi = Lpi::new("simple", ObjSense::Maximize).unwrap();
+        let inf = lpi.infinity();
+        lpi.add_col(3.0, 0.0, inf, &[]).unwrap();
+        lpi.add_col(4.0, 0.0, inf, &[]).unwrap();
+        lpi.add_row(-inf, 100.0, &[(0, 2.0), (1, 1.0)]).unwrap();
+        lpi.add_row(-inf, 80.0, &[(0, 1.0), (1, 2.0)]).unwrap();
+        lpi
+    }
+
+    #[test]
+    fn solve() {
+        let mut lpi = simple_lp();
+        assert_eq!(lpi.n_cols().unwrap(), 2);
+        assert_eq!(lpi.n_rows().unwrap(), 2);
+
+        lpi.solve_primal().unwrap();
+        assert!(lpi.is_optimal());
+        assert!(!lpi.is_primal_infeasible());
+        let sol = lpi.sol().unwrap();
+        assert!((sol.obj_val - 200.0).abs() < 1e-6);
+        assert!((sol.primal[0] - 40.0).abs() < 1e-6);
+        assert!((sol.primal[1] - 20.0).abs() < 1e-6);
+        assert!((sol.dual[0] - 2.0 / 3.0).abs() < 1e-6);
+        assert!((sol.dual[1] - 5.0 / 3.0).abs() < 1e-6);
+        assert!((sol.activity[0] - 100.0).abs() < 1e-6);
+
+        let (cstat, rstat) = lpi.basis().unwrap();
+        assert_eq!(cstat, vec![BasisStatus::Basic; 2]);
+        assert!(rstat.iter().all(|s| *s != BasisStatus::Basic));
+    }
+
+    #[test]
+    fn infeasible() {
+        let mut lpi = simple_lp();
+        let inf = lpi.infinity();
+        lpi.chg_sides(0, -inf, -10.0).unwrap();
+        lpi.solve_dual().unwrap();
+        assert!(lpi.is_primal_infeasible());
+    }
+
+    #[test]
+    fn invalid_positions() {
+        let mut lpi = simple_lp();
+        let inf = lpi.infinity();
+        let invalid_data =
+            |res: Result<(), Error>| assert_eq!(res.unwrap_err().retcode(), Retcode::InvalidData);
+        invalid_data(lpi.add_col(1.0, 0.0, inf, &[(2, 1.0)]).map(drop));
+        invalid_data(lpi.add_row(0.0, 1.0, &[(usize::MAX, 1.0)]).map(drop));
+        invalid_data(lpi.chg_bounds(2, 0.0, 1.0));
+        invalid_data(lpi.chg_obj(c_int::MAX as usize + 1, 1.0));
+        invalid_data(lpi.chg_sides(2, 0.0, 1.0));
+        assert_eq!(lpi.n_cols().unwrap(), 2);
+        assert_eq!(lpi.n_rows().unwrap(), 2);
+    }
+
+    #[test]
+    fn ranging() {
+        let mut lpi = simple_lp();
+        lpi.solve_primal().unwrap();
+        let inf = lpi.infinity();
+
+        let close = |(a, b): (f64, f64), (c, d): (f64, f64)| {
+            assert!((a - c).abs() < 1e-6 && (b - d).abs() < 1e-6, "{a}, {b}")
+        };
+        close(lpi.obj_ranging(0).unwrap(), (2.0, 8.0));
+        close(lpi.obj_ranging(1).unwrap(), (1.5, 6.0));
+        close(lpi.rhs_ranging(0).unwrap(), (40.0, 160.0));
+        close(lpi.rhs_ranging(1).unwrap(), (50.0, 200.0));
+        assert_eq!(
+            lpi.obj_ranging(2).unwrap_err().retcode(),
+            Retcode::InvalidData
+        );
+
+        // a nonbasic column can become attractive once its reduced cost vanishes
+        lpi.add_col(1.0, 0.0, inf, &[(0, 1.0), (1, 1.0)]).unwrap();
+        lpi.solve_primal().unwrap();
+        let sol = lpi.sol().unwrap();
+        assert!((sol.redcost[2] + 4.0 / 3.0).abs() < 1e-6);
+        close(lpi.obj_ranging(2).unwrap(), (-inf, 7.0 / 3.0));
+    }
+
+    #[test]
+    fn warm_start() {
+        let mut lpi = simple_lp();
+        lpi.solve_dual().unwrap();
+        let state = lpi.state().unwrap();
+        let (cstat, rstat) = lpi.basis().unwrap();
+
+        lpi.chg_obj(0, 0.0).unwrap();
+        lpi.solve_primal().unwrap();
+        assert!((lpi.obj_val().unwrap() - 160.0).abs() < 1e-6);
+
+        // going back to the original objective, the stored basis is optimal again
+        lpi.chg_obj(0, 3.0).unwrap();
+        lpi.set_state(&state).unwrap();
+        lpi.solve_primal().unwrap();
+        assert!(lpi.is_optimal());
+        assert_eq!(lpi.iterations().unwrap(), 0);
+
+        lpi.set_basis(&cstat, &rstat).unwrap();
+        lpi.solve_dual().unwrap();
+        assert_eq!(lpi.iterations().unwrap(), 0);
+        assert!((lpi.obj_val().unwrap() - 200.0).abs() < 1e-6);
+
+        let other = simple_lp();
+        let foreign = other.state().unwrap();
+        assert_eq!(
+            lpi.set_state(&foreign).unwrap_err().retcode(),
+            Retcode::InvalidData
+        );
+    }
+}
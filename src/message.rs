@@ -0,0 +1,427 @@
+use crate::ffi;
+use std::cell::RefCell;
+use std::ffi::CStr;
+use std::rc::{Rc, Weak};
+use std::sync::Once;
+
+/// A trait for receiving SCIP's output (log lines, warnings and errors) instead of printing it to stdout.
+///
+/// Each method is called once per line of output, without the trailing newline.
+pub trait MessageHandler {
+    /// Called for regular output, e.g. the progress table and the final statistics.
+    fn info(&mut self, msg: &str);
+
+    /// Called for warnings.
+    fn warning(&mut self, msg: &str);
+
+    /// Called for output of SCIP's interactive shell. Defaults to `info`.
+    fn dialog(&mut self, msg: &str) {
+        self.info(msg)
+    }
+
+    /// Called for error messages. Defaults to `warning`.
+    ///
+    /// SCIP reports errors through a process-wide printer; russcip routes the errors of a failing call to the
+    /// message handler of the model making the call, or to stderr if the model has none.
+    fn error(&mut self, msg: &str) {
+        self.warning(msg)
+    }
+}
+
+impl std::fmt::Debug for dyn MessageHandler {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("MessageHandler")
+    }
+}
+
+/// The channel a SCIP message was emitted on.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum MessageLevel {
+    /// Regular output.
+    Info,
+    /// A warning.
+    Warning,
+    /// Output of SCIP's interactive shell.
+    Dialog,
+    /// An error message.
+    Error,
+}
+
+impl<F: FnMut(MessageLevel, &str)> MessageHandler for F {
+    fn info(&mut self, msg: &str) {
+        self(MessageLevel::Info, msg)
+    }
+
+    fn warning(&mut self, msg: &str) {
+        self(MessageLevel::Warning, msg)
+    }
+
+    fn dialog(&mut self, msg: &str) {
+        self(MessageLevel::Dialog, msg)
+    }
+
+    fn error(&mut self, msg: &str) {
+        self(MessageLevel::Error, msg)
+    }
+}
+
+/// A message handler that forwards SCIP's output to the `log` crate with target `scip`.
+#[cfg(feature = "log")]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct LogMessageHandler;
+
+#[cfg(feature = "log")]
+impl MessageHandler for LogMessageHandler {
+    fn info(&mut self, msg: &str) {
+        log::info!(target: "scip", "{msg}");
+    }
+
+    fn warning(&mut self, msg: &str) {
+        log::warn!(target: "scip", "{msg}");
+    }
+
+    fn error(&mut self, msg: &str) {
+        log::error!(target: "scip", "{msg}");
+    }
+}
+
+/// A message handler that forwards SCIP's output to the `tracing` crate with target `scip`.
+#[cfg(feature = "tracing")]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct TracingMessageHandler;
+
+#[cfg(feature = "tracing")]
+impl MessageHandler for TracingMessageHandler {
+    fn info(&mut self, msg: &str) {
+        tracing::info!(target: "scip", "{msg}");
+    }
+
+    fn warning(&mut self, msg: &str) {
+        tracing::warn!(target: "scip", "{msg}");
+    }
+
+    fn error(&mut self, msg: &str) {
+        tracing::error!(target: "scip", "{msg}");
+    }
+}
+
+pub(crate) type SharedMessageHandler = Rc<RefCell<Box<dyn MessageHandler>>>;
+type WeakMessageHandler = Weak<RefCell<Box<dyn MessageHandler>>>;
+
+/// Data attached to a SCIP message handler, buffers partial lines per channel.
+pub(crate) struct MessageHandlerData {
+    pub(crate) handler: SharedMessageHandler,
+    buffers: [String; 3],
+}
+
+impl MessageHandlerData {
+    pub(crate) fn new(handler: SharedMessageHandler) -> Self {
+        MessageHandlerData {
+            handler,
+            buffers: Default::default(),
+        }
+    }
+
+    /// Appends a message to the buffer of the given channel and dispatches all completed lines.
+    pub(crate) fn push(&mut self, level: MessageLevel, msg: &str) {
+        let idx = match level {
+            MessageLevel::Info => 0,
+            MessageLevel::Warning => 1,
+            MessageLevel::Dialog | MessageLevel::Error => 2,
+        };
+        let buffer = &mut self.buffers[idx];
+        buffer.push_str(msg);
+        while let Some(pos) = buffer.find('\n') {
+            let line: String = buffer.drain(..=pos).collect();
+            dispatch(&self.handler, level, line.trim_end_matches(['\n', '\r']));
+        }
+    }
+
+    /// Dispatches any remaining partial lines.
+    pub(crate) fn flush(&mut self) {
+        let levels = [
+            MessageLevel::Info,
+            MessageLevel::Warning,
+            MessageLevel::Dialog,
+        ];
+        for (buffer, level) in self.buffers.iter_mut().zip(levels) {
+            if !buffer.is_empty() {
+                let line = std::mem::take(buffer);
+                dispatch(&self.handler, level, &line);
+            }
+        }
+    }
+}
+
+fn dispatch(handler: &SharedMessageHandler, level: MessageLevel, line: &str) {
+    // a handler calling back into SCIP could emit a message while already borrowed, print it instead of panicking
+    let Ok(mut handler) = handler.try_borrow_mut() else {
+        eprintln!("{line}");
+        return;
+    };
+    match level {
+        MessageLevel::Info => handler.info(line),
+        MessageLevel::Warning => handler.warning(line),
+        MessageLevel::Dialog => handler.dialog(line),
+        MessageLevel::Error => handler.error(line),
+    }
+}
+
+thread_local! {
+    static ERROR_HANDLER: RefCell<Option<WeakMessageHandler>> = const { RefCell::new(None) };
+    static ERROR_BUFFER: RefCell<String> = const { RefCell::new(String::new()) };
+    static ERROR_CAPTURES: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+static INSTALL_ERROR_PRINTER: Once = Once::new();
+
+fn install_error_printer() {
+    INSTALL_ERROR_PRINTER.call_once(|| unsafe {
+        ffi::SCIPmessageSetErrorPrinting(Some(error_printer), std::ptr::null_mut());
+    });
+}
+
+/// Routes SCIP's error messages on the current thread to the given handler, or to stderr for `None`, while running
+/// the given function. The previous route is restored afterwards, so that nested calls of other models do not take
+/// over the errors.
+pub(crate) fn with_error_route<T>(
+    handler: Option<&SharedMessageHandler>,
+    f: impl FnOnce() -> T,
+) -> T {
+    struct RestoreRoute(Option<WeakMessageHandler>);
+
+    impl Drop for RestoreRoute {
+        fn drop(&mut self) {
+            flush_errors();
+            ERROR_HANDLER.with(|h| *h.borrow_mut() = self.0.take());
+        }
+    }
+
+    install_error_printer();
+    flush_errors();
+    let previous = ERROR_HANDLER.with(|h| h.replace(handler.map(Rc::downgrade)));
+    let _restore = RestoreRoute(previous);
+    f()
+}
+
+/// Dispatches a remaining partial error line to the current route.
+fn flush_errors() {
+    let line = ERROR_BUFFER.with(|buffer| std::mem::take(&mut *buffer.borrow_mut()));
+    if line.is_empty() {
+        return;
+    }
+    match ERROR_HANDLER.with(|h| h.borrow().as_ref().and_then(Weak::upgrade)) {
+        Some(handler) => dispatch(&handler, MessageLevel::Error, &line),
+        None => eprintln!("{line}"),
+    }
+}
+
+/// Runs the given function and returns its result together with the error messages SCIP emitted meanwhile on the
+/// current thread. The messages are still routed to the message handler (or stderr) as usual.
+pub(crate) fn capture_errors<T>(f: impl FnOnce() -> T) -> (T, String) {
+    install_error_printer();
+    ERROR_CAPTURES.with(|c| c.borrow_mut().push(String::new()));
+    let res = f();
+    let captured = ERROR_CAPTURES.with(|c| c.borrow_mut().pop().unwrap_or_default());
+    (res, captured)
+}
+
+unsafe extern "C" fn error_printer(
+    _data: *mut std::os::raw::c_void,
+    _file: *mut ffi::FILE,
+    msg: *const std::os::raw::c_char,
+) {
+    if msg.is_null() {
+        return;
+    }
+    let msg = unsafe { CStr::from_ptr(msg) }.to_string_lossy();
+    ERROR_CAPTURES.with(|c| {
+        if let Some(captured) = c.borrow_mut().last_mut() {
+            captured.push_str(&msg);
+        }
+    });
+    let handler = ERROR_HANDLER.with(|h| h.borrow().as_ref().and_then(Weak::upgrade));
+    let Some(handler) = handler else {
+        eprint!("{msg}");
+        return;
+    };
+    let lines = ERROR_BUFFER.with(|buffer| {
+        let mut buffer = buffer.borrow_mut();
+        buffer.push_str(&msg);
+        let mut lines = Vec::new();
+        while let Some(pos) = buffer.find('\n') {
+            let line: String = buffer.drain(..=pos).collect();
+            lines.push(line.trim_end_matches(['\n', '\r']).to_string());
+        }
+        lines
+    });
+    for line in lines {
+        dispatch(&handler, MessageLevel::Error, &line);
+    }
+}
+
+/// Returns whether the given file is the C standard output (where SCIP sends messages without an explicit file).
+pub(crate) fn is_stdout(file: *mut ffi::FILE) -> bool {
+    if file.is_null() {
+        return true;
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    {
+        extern "C" {
+            static mut stdout: *mut ffi::FILE;
+        }
+        file == unsafe { stdout }
+    }
+
+    // the BSD libc exports the standard streams under different names
+    #[cfg(any(
+        target_vendor = "apple",
+        target_os = "freebsd",
+        target_os = "dragonfly"
+    ))]
+    {
+        extern "C" {
+            static mut __stdoutp: *mut ffi::FILE;
+        }
+        file == unsafe { __stdoutp }
+    }
+
+    #[cfg(windows)]
+    {
+        extern "C" {
+            fn __acrt_iob_func(index: std::os::raw::c_uint) -> *mut ffi::FILE;
+        }
+        file == unsafe { __acrt_iob_func(1) }
+    }
+
+    // on other targets only messages without an explicit file are known to go to stdout
+    #[cfg(not(any(
+        target_os = "linux",
+        target_os = "android",
+        target_vendor = "apple",
+        target_os = "freebsd",
+        target_os = "dragonfly",
+        windows
+    )))]
+    {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn closure_handler() {
+        let messages = Rc::new(RefCell::new(Vec::new()));
+        let messages_clone = messages.clone();
+        let model = Model::new()
+            .set_message_handler(move |level: MessageLevel, msg: &str| {
+                messages_clone.borrow_mut().push((level, msg.to_string()))
+            })
+            .include_default_plugins()
+            .read_prob("data/test/simple.lp")
+            .unwrap()
+            .solve();
+        assert_eq!(model.status(), Status::Optimal);
+
+        let logged = messages.borrow();
+        assert!(logged
+            .iter()
+            .any(|(level, msg)| *level == MessageLevel::Info && msg.contains("SCIP Status")));
+        assert!(logged.iter().all(|(_, msg)| !msg.contains('\n')));
+        let n_logged = logged.len();
+        drop(logged);
+
+        // the captured statistics should not be routed to the handler
+        let stats = model.statistics_string().unwrap();
+        assert!(stats.contains("Presolvers"));
+        assert_eq!(messages.borrow().len(), n_logged);
+    }
+
+    #[test]
+    fn error_channel() {
+        let errors = Rc::new(RefCell::new(Vec::new()));
+        let errors_clone = errors.clone();
+        let res = Model::new()
+            .set_message_handler(move |level: MessageLevel, msg: &str| {
+                if level == MessageLevel::Error {
+                    errors_clone.borrow_mut().push(msg.to_string())
+                }
+            })
+            .set_int_param("display/verblevel", -1);
+        assert!(res.is_err());
+        assert!(errors.borrow().iter().any(|msg| msg.contains("verblevel")));
+    }
+
+    #[test]
+    fn errors_of_each_model() {
+        let errors_a = Rc::new(RefCell::new(Vec::new()));
+        let errors_b = Rc::new(RefCell::new(Vec::new()));
+        let collect = |errors: &Rc<RefCell<Vec<String>>>| {
+            let errors = errors.clone();
+            move |level: MessageLevel, msg: &str| {
+                if level == MessageLevel::Error {
+                    errors.borrow_mut().push(msg.to_string())
+                }
+            }
+        };
+        let model_a = Model::new().set_message_handler(collect(&errors_a));
+        let model_b = Model::new().set_message_handler(collect(&errors_b));
+        let model_c = Model::new();
+
+        // the errors go to the handler of the failing model, not to the one set last
+        assert!(model_a.set_int_param("display/verblevel", -1).is_err());
+        assert!(!errors_a.borrow().is_empty());
+        assert!(errors_b.borrow().is_empty());
+
+        let n_errors_a = errors_a.borrow().len();
+        assert!(model_c.set_int_param("display/verblevel", -1).is_err());
+        assert_eq!(errors_a.borrow().len(), n_errors_a);
+        assert!(errors_b.borrow().is_empty());
+
+        assert!(model_b.set_int_param("display/verblevel", -1).is_err());
+        assert!(!errors_b.borrow().is_empty());
+        assert_eq!(errors_a.borrow().len(), n_errors_a);
+    }
+
+    struct CountingHandler {
+        n_info: Rc<RefCell<usize>>,
+    }
+
+    impl MessageHandler for CountingHandler {
+        fn info(&mut self, _msg: &str) {
+            *self.n_info.borrow_mut() += 1;
+        }
+
+        fn warning(&mut self, _msg: &str) {}
+    }
+
+    #[test]
+    fn per_model_handler() {
+        let n_info = Rc::new(RefCell::new(0));
+        let handler = CountingHandler {
+            n_info: n_info.clone(),
+        };
+        Model::new()
+            .set_message_handler(handler)
+            .include_default_plugins()
+            .read_prob("data/test/simple.lp")
+            .unwrap()
+            .solve();
+        let n_first = *n_info.borrow();
+        assert!(n_first > 0);
+
+        // a model without handler does not touch the other model's handler
+        Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .read_prob("data/test/simple.lp")
+            .unwrap()
+            .solve();
+        assert_eq!(*n_info.borrow(), n_first);
+    }
+}
@@ -1,17 +1,34 @@
 use crate::builder::CanBeAddedToModel;
 use crate::constraint::Constraint;
+use crate::dive::DiveGuard;
+use crate::error::Error;
 use crate::eventhdlr::Eventhdlr;
+use crate::iis::{deletion_filter, Iis};
+use crate::interrupt::{InterruptEventhdlr, InterruptHandle};
+use crate::lp::{BasisIndex, LpSolStat};
 use crate::node::Node;
 use crate::param::ScipParameter;
+use crate::params::{Param, ParamInfo};
+use crate::probing::ProbingGuard;
 use crate::retcode::Retcode;
 use crate::scip::ScipPtr;
-use crate::solution::{SolError, Solution};
+use crate::solution::{SolError, Solution, SolutionValues};
+use crate::solve_async::{self, ProgressEventhdlr, SendModel, SolveHandle};
+use crate::stage::{
+    Stage, ADD_CONS_STAGES, ADD_SOL_STAGES, ADD_VAR_STAGES, CREATE_SOL_STAGES, PROBLEM_STAGES,
+    SOLVED_STAGES, SOLVE_STAGES, SOLVING_STAGES, STATS_STAGES, TREE_STAGES,
+};
+use crate::stats::{HeuristicStats, SeparatorStats, SolveSummary, Statistics};
 use crate::status::Status;
 use crate::variable::{VarId, VarType, Variable};
-use crate::{ffi, Row, Separator};
-use crate::{BranchRule, HeurTiming, Heuristic, Pricer};
+use crate::{ffi, BendersCut, Col, Row, Separator};
+use crate::{
+    BranchRule, DisplayColumn, DisplayStatus, HeurTiming, Heuristic, MessageHandler, Pricer,
+};
 use scip_sys::SCIP;
+use std::collections::HashMap;
 use std::rc::Rc;
+use std::sync::{Arc, Mutex};
 
 /// Represents an optimization model.
 #[non_exhaustive]
@@ -72,13 +89,18 @@ impl Model<PluginsIncluded> {
     /// This method panics if the problem cannot be created in the current state.
     #[allow(unused_mut)]
     pub fn create_prob(mut self, name: &str) -> Model<ProblemCreated> {
-        let mut scip = self.scip.clone();
-        scip.create_prob(name)
-            .expect("Failed to create problem in state PluginsIncluded");
-        Model {
-            scip,
+        self.try_create_prob(name)
+            .expect("Failed to create problem in state PluginsIncluded")
+    }
+
+    /// Fallible version of [`create_prob`](Model::create_prob), returns an [`Error`] instead of panicking.
+    pub fn try_create_prob(self, name: &str) -> Result<Model<ProblemCreated>, Error> {
+        self.scip
+            .try_call("create_prob", Some(name), |scip| scip.create_prob(name))?;
+        Ok(Model {
+            scip: self.scip,
             state: ProblemCreated {},
-        }
+        })
     }
 
     /// Reads a problem from the given file and returns a new `Model` instance with a `ProblemCreated` state.
@@ -89,11 +111,11 @@ impl Model<PluginsIncluded> {
     ///
     /// # Errors
     ///
-    /// This method returns a `Retcode` error if the problem cannot be read from the file.
+    /// This method returns an `Error` if the problem cannot be read from the file.
     #[allow(unused_mut)]
-    pub fn read_prob(mut self, filename: &str) -> Result<Model<ProblemCreated>, Retcode> {
+    pub fn read_prob(mut self, filename: &str) -> Result<Model<ProblemCreated>, Error> {
         let scip = self.scip.clone();
-        scip.read_prob(filename)?;
+        scip.try_call("read_prob", Some(filename), |scip| scip.read_prob(filename))?;
         let new_model = Model {
             scip: self.scip,
             state: ProblemCreated {},
@@ -112,12 +134,17 @@ impl Model<ProblemCreated> {
     /// # Panics
     ///
     /// This method panics if the objective sense cannot be set in the current state.
+    #[allow(unused_mut)]
     pub fn set_obj_sense(mut self, sense: ObjSense) -> Self {
-        let scip = self.scip.clone();
-        scip.set_obj_sense(sense)
-            .expect("Failed to set objective sense in state ProblemCreated");
-        self.scip = scip;
-        self
+        self.try_set_obj_sense(sense)
+            .expect("Failed to set objective sense in state ProblemCreated")
+    }
+
+    /// Fallible version of [`set_obj_sense`](Model::set_obj_sense), returns an [`Error`] instead of panicking.
+    pub fn try_set_obj_sense(self, sense: ObjSense) -> Result<Self, Error> {
+        self.scip
+            .try_call("set_obj_sense", None, |scip| scip.set_obj_sense(sense))?;
+        Ok(self)
     }
 
     /// Sets the objective sense of the model to maximize
@@ -126,26 +153,54 @@ impl Model<ProblemCreated> {
         self.set_obj_sense(ObjSense::Maximize)
     }
 
+    /// Fallible version of [`maximize`](Model::maximize), returns an [`Error`] instead of panicking.
+    pub fn try_maximize(self) -> Result<Self, Error> {
+        self.try_set_obj_sense(ObjSense::Maximize)
+    }
+
     /// Sets the objective sense of the model to minimize
     #[allow(unused_mut)]
     pub fn minimize(mut self) -> Self {
         self.set_obj_sense(ObjSense::Minimize)
     }
 
+    /// Fallible version of [`minimize`](Model::minimize), returns an [`Error`] instead of panicking.
+    pub fn try_minimize(self) -> Result<Self, Error> {
+        self.try_set_obj_sense(ObjSense::Minimize)
+    }
+
     /// Sets the constraint as modifiable or not.
     pub fn set_cons_modifiable(&mut self, cons: &Constraint, modifiable: bool) {
-        self.scip
-            .set_cons_modifiable(cons, modifiable)
+        self.try_set_cons_modifiable(cons, modifiable)
             .expect("Failed to set constraint modifiable");
     }
 
+    /// Fallible version of [`set_cons_modifiable`](Model::set_cons_modifiable), returns an [`Error`] instead of
+    /// panicking.
+    pub fn try_set_cons_modifiable(
+        &mut self,
+        cons: &Constraint,
+        modifiable: bool,
+    ) -> Result<(), Error> {
+        let name = cons.name();
+        self.scip
+            .try_call("set_cons_modifiable", Some(&name), |scip| {
+                scip.set_cons_modifiable(cons, modifiable)
+            })
+    }
+
     /// Informs the SCIP instance that the objective value is always integral and returns the same `Model` instance.
     #[allow(unused_mut)]
     pub fn set_obj_integral(mut self) -> Self {
+        self.try_set_obj_integral()
+            .expect("Failed to set the objective value as integral")
+    }
+
+    /// Fallible version of [`set_obj_integral`](Model::set_obj_integral), returns an [`Error`] instead of panicking.
+    pub fn try_set_obj_integral(self) -> Result<Self, Error> {
         self.scip
-            .set_obj_integral()
-            .expect("Failed to set the objective value as integral");
-        self
+            .try_call("set_obj_integral", None, |scip| scip.set_obj_integral())?;
+        Ok(self)
     }
 
     /// Adds a new variable to the model with the given lower bound, upper bound, objective coefficient, name, and type.
@@ -173,15 +228,29 @@ impl Model<ProblemCreated> {
         name: &str,
         var_type: VarType,
     ) -> Variable {
-        let var = self
-            .scip
-            .create_var(lb, ub, obj, name, var_type)
-            .expect("Failed to create variable in state ProblemCreated");
+        self.try_add_var(lb, ub, obj, name, var_type)
+            .expect("Failed to create variable in state ProblemCreated")
+    }
 
-        Variable {
+    /// Fallible version of [`add_var`](Model::add_var), returns an [`Error`] instead of panicking.
+    pub fn try_add_var(
+        &mut self,
+        lb: f64,
+        ub: f64,
+        obj: f64,
+        name: &str,
+        var_type: VarType,
+    ) -> Result<Variable, Error> {
+        self.scip
+            .check_stage("add_var", Some(name), ADD_VAR_STAGES)?;
+        let var = self.scip.try_call("add_var", Some(name), |scip| {
+            scip.create_var(lb, ub, obj, name, var_type)
+        })?;
+
+        Ok(Variable {
             raw: var,
             scip: self.scip.clone(),
-        }
+        })
     }
 
     /// Adds anything that could be added to the model (variables, constraints, etc.).
@@ -189,6 +258,11 @@ impl Model<ProblemCreated> {
         object.add(self)
     }
 
+    /// Fallible version of [`add`](Model::add), returns an [`Error`] instead of panicking.
+    pub fn try_add<R, O: CanBeAddedToModel<Return = R>>(&mut self, object: O) -> Result<R, Error> {
+        object.try_add(self)
+    }
+
     /// Includes a new branch rule in the model with the given name, description, priority, maximum depth, maximum bound distance, and implementation.
     ///
     /// # Arguments
@@ -212,11 +286,59 @@ impl Model<ProblemCreated> {
         maxbounddist: f64,
         rule: Box<dyn BranchRule>,
     ) {
-        self.scip
-            .include_branch_rule(name, desc, priority, maxdepth, maxbounddist, rule)
+        self.try_include_branch_rule(name, desc, priority, maxdepth, maxbounddist, rule)
             .expect("Failed to include branch rule at state ProblemCreated");
     }
 
+    /// Fallible version of [`include_branch_rule`](Model::include_branch_rule), returns an [`Error`] instead of
+    /// panicking.
+    pub fn try_include_branch_rule(
+        &mut self,
+        name: &str,
+        desc: &str,
+        priority: i32,
+        maxdepth: i32,
+        maxbounddist: f64,
+        rule: Box<dyn BranchRule>,
+    ) -> Result<(), Error> {
+        self.scip.thread_bound.set(true);
+        self.scip
+            .try_call("include_branch_rule", Some(name), |scip| {
+                scip.include_branch_rule(name, desc, priority, maxdepth, maxbounddist, rule)
+            })
+    }
+
+    /// Like [`include_branch_rule`](Model::include_branch_rule), for a branching rule that is `Send`, which keeps the model movable to
+    /// other threads with [`into_send`](Model::into_send).
+    pub fn include_branch_rule_send(
+        &mut self,
+        name: &str,
+        desc: &str,
+        priority: i32,
+        maxdepth: i32,
+        maxbounddist: f64,
+        rule: Box<dyn BranchRule + Send>,
+    ) {
+        self.try_include_branch_rule_send(name, desc, priority, maxdepth, maxbounddist, rule)
+            .expect("Failed to include branching rule at state ProblemCreated");
+    }
+
+    /// Fallible version of [`include_branch_rule_send`](Model::include_branch_rule_send), returns an [`Error`] instead of
+    /// panicking.
+    pub fn try_include_branch_rule_send(
+        &mut self,
+        name: &str,
+        desc: &str,
+        priority: i32,
+        maxdepth: i32,
+        maxbounddist: f64,
+        rule: Box<dyn BranchRule + Send>,
+    ) -> Result<(), Error> {
+        self.include_send(|model| {
+            model.try_include_branch_rule(name, desc, priority, maxdepth, maxbounddist, rule)
+        })
+    }
+
     /// Include a new primal heuristic in the model.
     ///
     /// # Arguments
@@ -244,8 +366,99 @@ impl Model<ProblemCreated> {
         usessubscip: bool,
         heur: Box<dyn Heuristic>,
     ) {
-        self.scip
-            .include_heur(
+        self.try_include_heur(
+            name,
+            desc,
+            priority,
+            dispchar,
+            freq,
+            freqofs,
+            maxdepth,
+            timing,
+            usessubscip,
+            heur,
+        )
+        .expect("Failed to include heuristic at state ProblemCreated");
+    }
+
+    /// Fallible version of [`include_heur`](Model::include_heur), returns an [`Error`] instead of panicking.
+    pub fn try_include_heur(
+        &mut self,
+        name: &str,
+        desc: &str,
+        priority: i32,
+        dispchar: char,
+        freq: i32,
+        freqofs: i32,
+        maxdepth: i32,
+        timing: HeurTiming,
+        usessubscip: bool,
+        heur: Box<dyn Heuristic>,
+    ) -> Result<(), Error> {
+        self.scip.thread_bound.set(true);
+        self.scip.try_call("include_heur", Some(name), |scip| {
+            scip.include_heur(
+                name,
+                desc,
+                priority,
+                dispchar,
+                freq,
+                freqofs,
+                maxdepth,
+                timing,
+                usessubscip,
+                heur,
+            )
+        })
+    }
+
+    /// Like [`include_heur`](Model::include_heur), for a heuristic that is `Send`, which keeps the model movable to
+    /// other threads with [`into_send`](Model::into_send).
+    pub fn include_heur_send(
+        &mut self,
+        name: &str,
+        desc: &str,
+        priority: i32,
+        dispchar: char,
+        freq: i32,
+        freqofs: i32,
+        maxdepth: i32,
+        timing: HeurTiming,
+        usessubscip: bool,
+        heur: Box<dyn Heuristic + Send>,
+    ) {
+        self.try_include_heur_send(
+            name,
+            desc,
+            priority,
+            dispchar,
+            freq,
+            freqofs,
+            maxdepth,
+            timing,
+            usessubscip,
+            heur,
+        )
+        .expect("Failed to include heuristic at state ProblemCreated");
+    }
+
+    /// Fallible version of [`include_heur_send`](Model::include_heur_send), returns an [`Error`] instead of
+    /// panicking.
+    pub fn try_include_heur_send(
+        &mut self,
+        name: &str,
+        desc: &str,
+        priority: i32,
+        dispchar: char,
+        freq: i32,
+        freqofs: i32,
+        maxdepth: i32,
+        timing: HeurTiming,
+        usessubscip: bool,
+        heur: Box<dyn Heuristic + Send>,
+    ) -> Result<(), Error> {
+        self.include_send(|model| {
+            model.try_include_heur(
                 name,
                 desc,
                 priority,
@@ -257,7 +470,7 @@ impl Model<ProblemCreated> {
                 usessubscip,
                 heur,
             )
-            .expect("Failed to include heuristic at state ProblemCreated");
+        })
     }
 
     /// Includes a new separator in the model.
@@ -283,8 +496,35 @@ impl Model<ProblemCreated> {
         delay: bool,
         separator: Box<dyn Separator>,
     ) {
-        self.scip
-            .include_separator(
+        self.try_include_separator(
+            name,
+            desc,
+            priority,
+            freq,
+            maxbounddist,
+            usesubscip,
+            delay,
+            separator,
+        )
+        .expect("Failed to include separator at state ProblemCreated");
+    }
+
+    /// Fallible version of [`include_separator`](Model::include_separator), returns an [`Error`] instead of
+    /// panicking.
+    pub fn try_include_separator(
+        &mut self,
+        name: &str,
+        desc: &str,
+        priority: i32,
+        freq: i32,
+        maxbounddist: f64,
+        usesubscip: bool,
+        delay: bool,
+        separator: Box<dyn Separator>,
+    ) -> Result<(), Error> {
+        self.scip.thread_bound.set(true);
+        self.scip.try_call("include_separator", Some(name), |scip| {
+            scip.include_separator(
                 name,
                 desc,
                 priority,
@@ -294,7 +534,155 @@ impl Model<ProblemCreated> {
                 delay,
                 separator,
             )
-            .expect("Failed to include separator at state ProblemCreated");
+        })
+    }
+
+    /// Like [`include_separator`](Model::include_separator), for a separator that is `Send`, which keeps the model movable to
+    /// other threads with [`into_send`](Model::into_send).
+    pub fn include_separator_send(
+        &mut self,
+        name: &str,
+        desc: &str,
+        priority: i32,
+        freq: i32,
+        maxbounddist: f64,
+        usesubscip: bool,
+        delay: bool,
+        separator: Box<dyn Separator + Send>,
+    ) {
+        self.try_include_separator_send(
+            name,
+            desc,
+            priority,
+            freq,
+            maxbounddist,
+            usesubscip,
+            delay,
+            separator,
+        )
+        .expect("Failed to include separator at state ProblemCreated");
+    }
+
+    /// Fallible version of [`include_separator_send`](Model::include_separator_send), returns an [`Error`] instead of
+    /// panicking.
+    pub fn try_include_separator_send(
+        &mut self,
+        name: &str,
+        desc: &str,
+        priority: i32,
+        freq: i32,
+        maxbounddist: f64,
+        usesubscip: bool,
+        delay: bool,
+        separator: Box<dyn Separator + Send>,
+    ) -> Result<(), Error> {
+        self.include_send(|model| {
+            model.try_include_separator(
+                name,
+                desc,
+                priority,
+                freq,
+                maxbounddist,
+                usesubscip,
+                delay,
+                separator,
+            )
+        })
+    }
+
+    /// Includes a new display column in the progress table of the model.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the display column. This should be a unique identifier.
+    /// * `desc` - A brief description of the display column.
+    /// * `header` - The header of the column in the progress table.
+    /// * `width` - The width of the column in characters.
+    /// * `priority` - The priority of the column; columns with lower priority are dropped first if the table is too wide.
+    /// * `position` - The relative position of the column; columns with smaller positions are shown further left.
+    /// * `stripline` - Whether a vertical bar should be printed after the column.
+    /// * `status` - Whether the column is shown.
+    /// * `column` - The display column to be included. This should be an object that implements the `DisplayColumn` trait.
+    pub fn include_display_column(
+        &mut self,
+        name: &str,
+        desc: &str,
+        header: &str,
+        width: usize,
+        priority: i32,
+        position: i32,
+        stripline: bool,
+        status: DisplayStatus,
+        column: Box<dyn DisplayColumn>,
+    ) {
+        self.try_include_display_column(
+            name, desc, header, width, priority, position, stripline, status, column,
+        )
+        .expect("Failed to include display column at state ProblemCreated");
+    }
+
+    /// Fallible version of [`include_display_column`](Model::include_display_column), returns an [`Error`] instead
+    /// of panicking.
+    pub fn try_include_display_column(
+        &mut self,
+        name: &str,
+        desc: &str,
+        header: &str,
+        width: usize,
+        priority: i32,
+        position: i32,
+        stripline: bool,
+        status: DisplayStatus,
+        column: Box<dyn DisplayColumn>,
+    ) -> Result<(), Error> {
+        self.scip.thread_bound.set(true);
+        self.scip
+            .try_call("include_display_column", Some(name), |scip| {
+                scip.include_display_column(
+                    name, desc, header, width, priority, position, stripline, status, column,
+                )
+            })
+    }
+
+    /// Like [`include_display_column`](Model::include_display_column), for a display column that is `Send`, which keeps the model movable to
+    /// other threads with [`into_send`](Model::into_send).
+    pub fn include_display_column_send(
+        &mut self,
+        name: &str,
+        desc: &str,
+        header: &str,
+        width: usize,
+        priority: i32,
+        position: i32,
+        stripline: bool,
+        status: DisplayStatus,
+        column: Box<dyn DisplayColumn + Send>,
+    ) {
+        self.try_include_display_column_send(
+            name, desc, header, width, priority, position, stripline, status, column,
+        )
+        .expect("Failed to include display column at state ProblemCreated");
+    }
+
+    /// Fallible version of [`include_display_column_send`](Model::include_display_column_send), returns an [`Error`] instead of
+    /// panicking.
+    pub fn try_include_display_column_send(
+        &mut self,
+        name: &str,
+        desc: &str,
+        header: &str,
+        width: usize,
+        priority: i32,
+        position: i32,
+        stripline: bool,
+        status: DisplayStatus,
+        column: Box<dyn DisplayColumn + Send>,
+    ) -> Result<(), Error> {
+        self.include_send(|model| {
+            model.try_include_display_column(
+                name, desc, header, width, priority, position, stripline, status, column,
+            )
+        })
     }
 
     /// Includes a new event handler in the model.
@@ -305,11 +693,79 @@ impl Model<ProblemCreated> {
     /// * `desc` - A brief description of the event handler. This is used for informational purposes.
     /// * `eventhdlr` - The event handler to be included. This should be a mutable reference to an object that implements the `EventHdlr` trait, and represents the event handling logic.
     pub fn include_eventhdlr(&mut self, name: &str, desc: &str, eventhdlr: Box<dyn Eventhdlr>) {
-        self.scip
-            .include_eventhdlr(name, desc, eventhdlr)
+        self.try_include_eventhdlr(name, desc, eventhdlr)
+            .expect("Failed to include event handler at state ProblemCreated");
+    }
+
+    /// Fallible version of [`include_eventhdlr`](Model::include_eventhdlr), returns an [`Error`] instead of
+    /// panicking.
+    pub fn try_include_eventhdlr(
+        &mut self,
+        name: &str,
+        desc: &str,
+        eventhdlr: Box<dyn Eventhdlr>,
+    ) -> Result<(), Error> {
+        self.scip.thread_bound.set(true);
+        self.scip.try_call("include_eventhdlr", Some(name), |scip| {
+            scip.include_eventhdlr(name, desc, eventhdlr)
+        })
+    }
+
+    /// Like [`include_eventhdlr`](Model::include_eventhdlr), for a event handler that is `Send`, which keeps the model movable to
+    /// other threads with [`into_send`](Model::into_send).
+    pub fn include_eventhdlr_send(
+        &mut self,
+        name: &str,
+        desc: &str,
+        eventhdlr: Box<dyn Eventhdlr + Send>,
+    ) {
+        self.try_include_eventhdlr_send(name, desc, eventhdlr)
             .expect("Failed to include event handler at state ProblemCreated");
     }
 
+    /// Fallible version of [`include_eventhdlr_send`](Model::include_eventhdlr_send), returns an [`Error`] instead of
+    /// panicking.
+    pub fn try_include_eventhdlr_send(
+        &mut self,
+        name: &str,
+        desc: &str,
+        eventhdlr: Box<dyn Eventhdlr + Send>,
+    ) -> Result<(), Error> {
+        self.include_send(|model| model.try_include_eventhdlr(name, desc, eventhdlr))
+    }
+
+    /// Returns a thread-safe handle that can be used to interrupt the solving process of this model, see
+    /// [`InterruptHandle`].
+    ///
+    /// All handles returned for the same model share their state.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the event handler that checks for interrupt requests cannot be included.
+    pub fn interrupt_handle(&mut self) -> InterruptHandle {
+        self.try_interrupt_handle()
+            .expect("Failed to include event handler at state ProblemCreated")
+    }
+
+    /// Fallible version of [`interrupt_handle`](Model::interrupt_handle), returns an [`Error`] instead of panicking.
+    pub fn try_interrupt_handle(&mut self) -> Result<InterruptHandle, Error> {
+        if let Some(handle) = self.scip.interrupt.get() {
+            return Ok(handle.clone());
+        }
+        let handle = InterruptHandle::default();
+        self.scip
+            .try_call("interrupt_handle", Some(InterruptEventhdlr::NAME), |scip| {
+                scip.include_eventhdlr(
+                    InterruptEventhdlr::NAME,
+                    "interrupts the solving process on request of an InterruptHandle",
+                    Box::new(InterruptEventhdlr::new(&handle)),
+                )
+            })?;
+        // the cell was checked to be empty above
+        let _ = self.scip.interrupt.set(handle.clone());
+        Ok(handle)
+    }
+
     /// Includes a new pricer in the SCIP data structure.
     ///
     /// # Arguments
@@ -331,11 +787,163 @@ impl Model<ProblemCreated> {
         delay: bool,
         pricer: Box<dyn Pricer>,
     ) {
-        self.scip
-            .include_pricer(name, desc, priority, delay, pricer)
+        self.try_include_pricer(name, desc, priority, delay, pricer)
+            .expect("Failed to include pricer at state ProblemCreated");
+    }
+
+    /// Fallible version of [`include_pricer`](Model::include_pricer), returns an [`Error`] instead of panicking.
+    pub fn try_include_pricer(
+        &mut self,
+        name: &str,
+        desc: &str,
+        priority: i32,
+        delay: bool,
+        pricer: Box<dyn Pricer>,
+    ) -> Result<(), Error> {
+        self.scip.thread_bound.set(true);
+        self.scip.try_call("include_pricer", Some(name), |scip| {
+            scip.include_pricer(name, desc, priority, delay, pricer)
+        })
+    }
+
+    /// Like [`include_pricer`](Model::include_pricer), for a pricer that is `Send`, which keeps the model movable to
+    /// other threads with [`into_send`](Model::into_send).
+    pub fn include_pricer_send(
+        &mut self,
+        name: &str,
+        desc: &str,
+        priority: i32,
+        delay: bool,
+        pricer: Box<dyn Pricer + Send>,
+    ) {
+        self.try_include_pricer_send(name, desc, priority, delay, pricer)
             .expect("Failed to include pricer at state ProblemCreated");
     }
 
+    /// Fallible version of [`include_pricer_send`](Model::include_pricer_send), returns an [`Error`] instead of
+    /// panicking.
+    pub fn try_include_pricer_send(
+        &mut self,
+        name: &str,
+        desc: &str,
+        priority: i32,
+        delay: bool,
+        pricer: Box<dyn Pricer + Send>,
+    ) -> Result<(), Error> {
+        self.include_send(|model| model.try_include_pricer(name, desc, priority, delay, pricer))
+    }
+
+    /// Decomposes the model with SCIP's default Benders' decomposition, where this model becomes the master problem.
+    ///
+    /// The master problem keeps the first-stage variables and constraints, while each subproblem holds second-stage
+    /// variables and constraints together with copies of the master variables they depend on. Master and subproblem
+    /// variables are linked by their names. SCIP adds an auxiliary variable for the objective of each subproblem to
+    /// the master problem and solves the subproblems whenever a master solution is enforced or checked, adding
+    /// optimality and feasibility cuts. Presolving of the subproblems should be turned off, as it can remove the
+    /// linking variables.
+    ///
+    /// The subproblems are kept alive as long as this model. Moving the model to another thread, e.g. with
+    /// `solve_async`, requires that no variables or constraints of the subproblems are alive anymore.
+    ///
+    /// # Arguments
+    ///
+    /// * `subproblems` - The subproblems of the decomposition.
+    ///
+    /// # Returns
+    ///
+    /// An `Error` if the decomposition cannot be created, e.g. if it was already created for this model.
+    pub fn add_benders_subproblems(
+        &mut self,
+        subproblems: Vec<Model<ProblemCreated>>,
+    ) -> Result<(), Error> {
+        self.scip
+            .check_stage("add_benders_subproblems", None, PROBLEM_STAGES)?;
+        let subproblems: Vec<_> = subproblems.into_iter().map(|model| model.scip).collect();
+        if subproblems.iter().any(|scip| scip.thread_bound.get()) {
+            self.scip.thread_bound.set(true);
+        }
+        self.scip.try_call("add_benders_subproblems", None, |scip| {
+            scip.create_benders_default(subproblems)
+        })
+    }
+
+    /// Includes a custom cut into the default Benders' decomposition of the model, which must already have been
+    /// created with `add_benders_subproblems`.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the cut. This should be a unique identifier.
+    /// * `desc` - A brief description of the cut.
+    /// * `priority` - The priority of the cut. Cuts with a higher priority are executed first, the default cuts of
+    ///   SCIP have priorities between 0 and 10000.
+    /// * `islpcut` - Whether the cut is generated from an LP subproblem.
+    /// * `cut` - The cut to be included. This should be an object that implements the `BendersCut` trait.
+    ///
+    /// # Returns
+    ///
+    /// An `Error` with `Retcode::InvalidCall` if the model has no Benders' decomposition, or another `Error` if the
+    /// cut cannot be included, e.g. because a cut with the same name already exists.
+    pub fn include_benders_cut(
+        &mut self,
+        name: &str,
+        desc: &str,
+        priority: i32,
+        islpcut: bool,
+        cut: Box<dyn BendersCut>,
+    ) -> Result<(), Error> {
+        self.scip
+            .check_stage("include_benders_cut", Some(name), PROBLEM_STAGES)?;
+        self.scip.thread_bound.set(true);
+        self.scip
+            .try_call("include_benders_cut", Some(name), |scip| {
+                scip.include_benders_cut(name, desc, priority, islpcut, cut)
+            })
+    }
+
+    /// Like [`include_benders_cut`](Model::include_benders_cut), for a cut that is `Send`, which keeps the model
+    /// movable to other threads with [`into_send`](Model::into_send).
+    pub fn include_benders_cut_send(
+        &mut self,
+        name: &str,
+        desc: &str,
+        priority: i32,
+        islpcut: bool,
+        cut: Box<dyn BendersCut + Send>,
+    ) -> Result<(), Error> {
+        self.include_send(|model| model.include_benders_cut(name, desc, priority, islpcut, cut))
+    }
+
+    /// Runs a function including a plugin that is `Send`, without binding the model to the current thread.
+    fn include_send(
+        &mut self,
+        include: impl FnOnce(&mut Self) -> Result<(), Error>,
+    ) -> Result<(), Error> {
+        let thread_bound = self.scip.thread_bound.get();
+        let result = include(self);
+        self.scip.thread_bound.set(thread_bound);
+        result
+    }
+
+    /// Adds a solution snapshot (possibly taken from another model) as a start solution.
+    ///
+    /// Values are matched to the model's variables by name; variables missing from the snapshot are set to zero,
+    /// and names in the snapshot that are not in the model are ignored.
+    ///
+    /// # Returns
+    /// A `Result` indicating whether the solution was added successfully.
+    pub fn add_sol_values(&mut self, values: &SolutionValues) -> Result<(), SolError> {
+        let by_name = values
+            .iter()
+            .collect::<std::collections::HashMap<&str, f64>>();
+        let sol = self.create_sol();
+        for var in self.vars() {
+            if let Some(val) = by_name.get(var.name().as_str()) {
+                sol.set_val(&var, *val);
+            }
+        }
+        self.add_sol(sol)
+    }
+
     /// Solves the model and returns a new `Model` instance with a `Solved` state.
     ///
     /// # Returns
@@ -347,17 +955,240 @@ impl Model<ProblemCreated> {
     /// This method panics if the problem cannot be solved in the current state.
     #[allow(unused_mut)]
     pub fn solve(mut self) -> Model<Solved> {
+        self.try_solve()
+            .expect("Failed to solve problem in state ProblemCreated")
+    }
+
+    /// Fallible version of [`solve`](Model::solve), returns an [`Error`] instead of panicking.
+    #[allow(unused_mut)]
+    pub fn try_solve(mut self) -> Result<Model<Solved>, Error> {
+        self.scip.check_stage("solve", None, SOLVE_STAGES)?;
+        self.scip.try_call("solve", None, |scip| scip.solve())?;
+        Ok(Model {
+            scip: self.scip,
+            state: Solved {},
+        })
+    }
+
+    /// Solves the model with several concurrent solvers running different settings on `n_threads` threads, and
+    /// returns a new `Model` instance with a `Solved` state as soon as one of them has solved the problem.
+    ///
+    /// The settings of the concurrent solvers, e.g. how many of them emphasize feasibility or optimality, are
+    /// controlled by the parameters in [`params::concurrent`](crate::params::concurrent). The solvers work on
+    /// copies of the problem, so custom plugins of the model are not used. If SCIP was built without support for
+    /// parallel solving, the model is solved sequentially.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the problem cannot be solved in the current state or `n_threads` is not a valid number
+    /// of threads.
+    pub fn solve_concurrent(self, n_threads: usize) -> Model<Solved> {
+        self.try_solve_concurrent(n_threads)
+            .expect("Failed to solve problem concurrently in state ProblemCreated")
+    }
+
+    /// Fallible version of [`solve_concurrent`](Model::solve_concurrent), returns an [`Error`] instead of
+    /// panicking.
+    pub fn try_solve_concurrent(self, n_threads: usize) -> Result<Model<Solved>, Error> {
         self.scip
-            .solve()
-            .expect("Failed to solve problem in state ProblemCreated");
-        Model {
+            .check_stage("solve_concurrent", None, SOLVE_STAGES)?;
+        let n_threads = i32::try_from(n_threads)
+            .ok()
+            .filter(|&n| n > 0)
+            .ok_or_else(|| {
+                Error::new(Retcode::InvalidData, "solve_concurrent", None, self.stage())
+                    .with_message("the number of threads must be positive")
+            })?;
+        self.scip.try_call("solve_concurrent", None, |scip| {
+            scip.set_int_param("parallel/maxnthreads", n_threads)?;
+            scip.solve_concurrent()
+        })?;
+        Ok(Model {
             scip: self.scip,
             state: Solved {},
+        })
+    }
+
+    /// Counts the feasible solutions of the model with SCIP's solution counter, which searches the whole
+    /// branch-and-bound tree with the counting emphasis instead of optimizing. The model must only have integer
+    /// variables.
+    ///
+    /// # Arguments
+    ///
+    /// * `limit` - Stops counting once this many solutions were found, `None` counts all solutions.
+    ///
+    /// # Returns
+    ///
+    /// The number of solutions, and whether it is exact. The count is not exact if the limit was reached, in which
+    /// case it can be larger than the limit, or if it does not fit into a 64-bit integer.
+    pub fn count_solutions(self, limit: Option<usize>) -> Result<(u64, bool), Error> {
+        let model = self.count("count_solutions", limit, false)?;
+        let (count, valid) = model.scip.n_counted_sols();
+        Ok((count, valid && !model.status().is_limit()))
+    }
+
+    /// Enumerates the feasible solutions of the model with SCIP's solution counter, see
+    /// [`count_solutions`](Model::count_solutions). Presolving is turned off, as the solutions are collected on the
+    /// variables of the problem.
+    ///
+    /// # Arguments
+    ///
+    /// * `limit` - Returns at most this many solutions, `None` returns all solutions.
+    ///
+    /// # Returns
+    ///
+    /// The values of the original variables in each solution.
+    pub fn enumerate_solutions(self, limit: Option<usize>) -> Result<Vec<SolutionValues>, Error> {
+        let model = self.count("enumerate_solutions", limit, true)?;
+        Ok(model.scip.counted_sols(limit))
+    }
+
+    /// Runs SCIP's solution counter on the model, optionally collecting the solutions.
+    fn count(
+        self,
+        operation: &'static str,
+        limit: Option<usize>,
+        collect: bool,
+    ) -> Result<Model<Solved>, Error> {
+        self.scip.check_stage(operation, None, PROBLEM_STAGES)?;
+        if unsafe { ffi::SCIPgetNOrigContVars(self.scip.raw) } > 0 {
+            return Err(
+                Error::new(Retcode::InvalidData, operation, None, self.stage()).with_message(
+                    "counting solutions requires a model without continuous variables",
+                ),
+            );
         }
+        self.scip
+            .try_call(operation, None, |scip| scip.count(limit, collect))?;
+        Ok(Model {
+            scip: self.scip,
+            state: Solved {},
+        })
+    }
+
+    /// Solves the model on a background thread and returns a [`SolveHandle`], which reports the progress of the
+    /// solving process, can interrupt it, and returns the solved model when joined.
+    ///
+    /// As the model is moved to another thread, this requires that no variables, constraints or solutions of the
+    /// model are alive anymore, and that no message handler or custom plugins were included, except through the
+    /// `_send` variants such as [`include_heur_send`](Model::include_heur_send). Otherwise an `InvalidCall` error is
+    /// returned.
+    pub fn solve_async(mut self) -> Result<SolveHandle, Error> {
+        self.scip.check_stage("solve_async", None, SOLVE_STAGES)?;
+        self.check_sendable("solve_async")?;
+
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let sender = Arc::new(Mutex::new(Some(sender)));
+        self.scip.try_call("solve_async", None, |scip| {
+            scip.include_eventhdlr(
+                ProgressEventhdlr::NAME,
+                "reports the progress of the solving process to a SolveHandle",
+                Box::new(ProgressEventhdlr::new(sender.clone())),
+            )
+        })?;
+        let interrupt = self.interrupt_handle();
+        Ok(solve_async::spawn(
+            SendModel(self),
+            sender,
+            receiver,
+            interrupt,
+        ))
     }
 }
 
 impl Model<Solving> {
+    /// Returns the solution status of the current LP.
+    pub fn lp_sol_stat(&self) -> LpSolStat {
+        self.scip.lp_sol_stat()
+    }
+
+    /// Returns the objective value of the current LP.
+    pub fn lp_obj_val(&self) -> f64 {
+        self.scip.lp_obj_val()
+    }
+
+    /// Returns whether the current LP solution is basic, i.e., the basis information is available.
+    pub fn is_lp_sol_basic(&self) -> bool {
+        self.scip.is_lp_sol_basic()
+    }
+
+    /// Returns the columns of the current LP, ordered by their LP position.
+    pub fn lp_cols(&self) -> Vec<Col> {
+        self.scip
+            .lp_cols()
+            .into_iter()
+            .map(|raw| Col {
+                raw,
+                scip: self.scip.clone(),
+            })
+            .collect()
+    }
+
+    /// Returns the rows of the current LP, ordered by their LP position.
+    pub fn lp_rows(&self) -> Vec<Row> {
+        self.scip
+            .lp_rows()
+            .into_iter()
+            .map(|raw| Row {
+                raw,
+                scip: self.scip.clone(),
+            })
+            .collect()
+    }
+
+    /// Returns, for each row of the LP basis, the column or row whose (slack) variable is basic in it.
+    ///
+    /// The basis information is only available if the current LP solution is basic, see
+    /// [`is_lp_sol_basic`](Model::is_lp_sol_basic).
+    pub fn lp_basis_ind(&self) -> Result<Vec<BasisIndex>, Error> {
+        self.scip
+            .try_call("lp_basis_ind", None, |scip| scip.lp_basis_ind())
+    }
+
+    /// Returns the given row of the inverse of the basis matrix, as a dense vector over the LP rows.
+    pub fn lp_binv_row(&self, row: usize) -> Result<Vec<f64>, Error> {
+        self.scip
+            .try_call("lp_binv_row", None, |scip| scip.lp_binv_row(row))
+    }
+
+    /// Returns the given row of the simplex tableau, i.e., the product of the given row of the inverse of the basis
+    /// matrix with the constraint matrix, as a dense vector over the LP columns.
+    pub fn lp_binva_row(&self, row: usize) -> Result<Vec<f64>, Error> {
+        self.scip
+            .try_call("lp_binva_row", None, |scip| scip.lp_binva_row(row))
+    }
+
+    /// Starts an LP dive at the current node, see [`DiveGuard`]. The dive ends when the returned guard is dropped.
+    ///
+    /// Diving is only possible while the LP of the current node is solved and no other dive or probing is active.
+    pub fn start_dive(&mut self) -> Result<DiveGuard<'_>, Error> {
+        self.scip.check_stage("start_dive", None, SOLVING_STAGES)?;
+        self.scip
+            .try_call("start_dive", None, |scip| scip.start_dive())?;
+        Ok(DiveGuard::new(self))
+    }
+
+    /// Returns whether an LP dive is active.
+    pub fn in_dive(&self) -> bool {
+        self.scip.in_dive()
+    }
+
+    /// Starts probing at the current node, see [`ProbingGuard`]. Probing ends when the returned guard is dropped.
+    ///
+    /// Probing is only possible if no other probing or dive is active.
+    pub fn start_probing(&mut self) -> Result<ProbingGuard<'_>, Error> {
+        self.scip
+            .check_stage("start_probing", None, SOLVING_STAGES)?;
+        self.scip
+            .try_call("start_probing", None, |scip| scip.start_probing())?;
+        Ok(ProbingGuard::new(self))
+    }
+
+    /// Returns whether probing is active.
+    pub fn in_probing(&self) -> bool {
+        self.scip.in_probing()
+    }
+
     /// Adds a new variable to the model with the given lower bound, upper bound, objective coefficient, name, and type.
     ///
     /// # Arguments
@@ -383,15 +1214,29 @@ impl Model<Solving> {
         name: &str,
         var_type: VarType,
     ) -> Variable {
-        let var = self
-            .scip
-            .create_var_solving(lb, ub, obj, name, var_type)
-            .expect("Failed to create variable in state ProblemCreated");
+        self.try_add_var(lb, ub, obj, name, var_type)
+            .expect("Failed to create variable in state ProblemCreated")
+    }
+
+    /// Fallible version of [`add_var`](Model::add_var), returns an [`Error`] instead of panicking.
+    pub fn try_add_var(
+        &mut self,
+        lb: f64,
+        ub: f64,
+        obj: f64,
+        name: &str,
+        var_type: VarType,
+    ) -> Result<Variable, Error> {
+        self.scip
+            .check_stage("add_var", Some(name), ADD_VAR_STAGES)?;
+        let var = self.scip.try_call("add_var", Some(name), |scip| {
+            scip.create_var_solving(lb, ub, obj, name, var_type)
+        })?;
 
-        Variable {
+        Ok(Variable {
             raw: var,
             scip: self.scip.clone(),
-        }
+        })
     }
 
     /// Returns the current node of the model.
@@ -400,11 +1245,19 @@ impl Model<Solving> {
     ///
     /// This method panics if not called in the `Solving` state, it should only be used from plugins implementations.
     pub fn focus_node(&self) -> Node {
-        let scip_node = self.scip.focus_node().expect("Failed to get focus node");
-        Node {
+        self.try_focus_node().expect("Failed to get focus node")
+    }
+
+    /// Fallible version of [`focus_node`](Model::focus_node), returns an [`Error`] instead of panicking.
+    pub fn try_focus_node(&self) -> Result<Node, Error> {
+        self.scip.check_stage("focus_node", None, TREE_STAGES)?;
+        let scip_node = self.scip.try_call("focus_node", None, |scip| {
+            scip.focus_node().ok_or(Retcode::InvalidCall)
+        })?;
+        Ok(Node {
             raw: scip_node,
             scip: self.scip.clone(),
-        }
+        })
     }
 
     /// Creates a new child node of the current node and returns it.
@@ -413,15 +1266,22 @@ impl Model<Solving> {
     ///
     /// This method panics if not called from plugins implementations.
     pub fn create_child(&mut self) -> Node {
+        self.try_create_child()
+            .expect("Failed to create child node in state ProblemCreated")
+    }
+
+    /// Fallible version of [`create_child`](Model::create_child), returns an [`Error`] instead of panicking.
+    pub fn try_create_child(&mut self) -> Result<Node, Error> {
+        self.scip
+            .check_stage("create_child", None, SOLVING_STAGES)?;
         let node_ptr = self
             .scip
-            .create_child()
-            .expect("Failed to create child node in state ProblemCreated");
+            .try_call("create_child", None, |scip| scip.create_child())?;
 
-        Node {
+        Ok(Node {
             raw: node_ptr,
             scip: self.scip.clone(),
-        }
+        })
     }
 
     /// Adds a new priced variable to the SCIP data structure.
@@ -445,15 +1305,29 @@ impl Model<Solving> {
         name: &str,
         var_type: VarType,
     ) -> Variable {
-        let var = self
-            .scip
-            .create_priced_var(lb, ub, obj, name, var_type)
-            .expect("Failed to create variable in state ProblemCreated");
+        self.try_add_priced_var(lb, ub, obj, name, var_type)
+            .expect("Failed to create variable in state ProblemCreated")
+    }
+
+    /// Fallible version of [`add_priced_var`](Model::add_priced_var), returns an [`Error`] instead of panicking.
+    pub fn try_add_priced_var(
+        &mut self,
+        lb: f64,
+        ub: f64,
+        obj: f64,
+        name: &str,
+        var_type: VarType,
+    ) -> Result<Variable, Error> {
+        self.scip
+            .check_stage("add_priced_var", Some(name), SOLVING_STAGES)?;
+        let var = self.scip.try_call("add_priced_var", Some(name), |scip| {
+            scip.create_priced_var(lb, ub, obj, name, var_type)
+        })?;
 
-        Variable {
+        Ok(Variable {
             raw: var,
             scip: self.scip.clone(),
-        }
+        })
     }
 
     /// Gets the variable in current problem given its index (in the problem).
@@ -481,13 +1355,64 @@ impl Model<Solving> {
     /// # Returns
     /// A boolean indicating whether the row is infeasible from the local bounds.
     pub fn add_cut(&mut self, cut: Row, force_cut: bool) -> bool {
-        self.scip
-            .add_row(cut, force_cut)
+        self.try_add_cut(cut, force_cut)
             .expect("Failed to add row in state ProblemCreated")
     }
+
+    /// Fallible version of [`add_cut`](Model::add_cut), returns an [`Error`] instead of panicking.
+    pub fn try_add_cut(&mut self, cut: Row, force_cut: bool) -> Result<bool, Error> {
+        self.scip.check_stage("add_cut", None, SOLVING_STAGES)?;
+        self.scip
+            .try_call("add_cut", None, |scip| scip.add_row(cut, force_cut))
+    }
 }
 
 impl Model<Solved> {
+    /// Continues the solving process, e.g. after it was interrupted through an [`InterruptHandle`] or after a limit
+    /// that stopped it was raised. SCIP continues with the same branch-and-bound tree, so statistics like the number
+    /// of nodes and the solving time accumulate over all calls.
+    ///
+    /// If the problem was already solved (see [`is_resumable`](Model::is_resumable)), this does nothing.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use russcip::prelude::*;
+    /// # use russcip::params;
+    /// let model = Model::new()
+    ///     .include_default_plugins()
+    ///     .read_prob("data/test/gen-ip054.mps")
+    ///     .unwrap()
+    ///     .set(params::limits::Time, 10.0)
+    ///     .solve();
+    /// if model.status() == Status::TimeLimit {
+    ///     let model = model.set(params::limits::Time, 60.0).solve();
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the solving process cannot be continued.
+    #[allow(unused_mut)]
+    pub fn solve(mut self) -> Model<Solved> {
+        self.try_solve()
+            .expect("Failed to continue solving in state Solved")
+    }
+
+    /// Fallible version of [`solve`](Model::<Solved>::solve), returns an [`Error`] instead of panicking.
+    #[allow(unused_mut)]
+    pub fn try_solve(mut self) -> Result<Model<Solved>, Error> {
+        self.scip.check_stage("solve", None, SOLVE_STAGES)?;
+        self.scip.try_call("solve", None, |scip| scip.solve())?;
+        Ok(self)
+    }
+
+    /// Returns whether the solving process was stopped before the problem was solved, and can thus be continued
+    /// with [`solve`](Model::<Solved>::solve).
+    pub fn is_resumable(&self) -> bool {
+        (Stage::InitPresolve..Stage::Solved).contains(&self.stage())
+    }
+
     /// Returns the objective value of the best solution found by the optimization model.
     pub fn obj_val(&self) -> f64 {
         self.scip.obj_val()
@@ -508,16 +1433,197 @@ impl Model<Solved> {
         self.scip.n_lp_iterations()
     }
 
+    /// Returns the primal-dual integral of the solving process.
+    pub fn primal_dual_integral(&self) -> f64 {
+        self.scip.primal_dual_integral()
+    }
+
+    /// Returns the dual bound of the root node.
+    pub fn root_dual_bound(&self) -> f64 {
+        self.scip.root_dual_bound()
+    }
+
+    /// Returns the time needed to solve the first LP of the root node.
+    pub fn root_lp_time(&self) -> f64 {
+        self.scip.first_lp_time()
+    }
+
+    /// Returns the time spent in presolving.
+    pub fn presolving_time(&self) -> f64 {
+        self.scip.presolving_time()
+    }
+
+    /// Returns the maximal depth of all processed nodes over all runs.
+    pub fn max_depth(&self) -> usize {
+        self.scip.max_depth()
+    }
+
+    /// Returns the number of restarts performed.
+    pub fn n_restarts(&self) -> usize {
+        self.scip.n_runs().saturating_sub(1)
+    }
+
+    /// Returns the number of feasible solutions found so far.
+    pub fn n_sols_found(&self) -> usize {
+        self.scip.n_sols_found()
+    }
+
+    /// Returns the number of cuts applied by each separator.
+    pub fn separator_stats(&self) -> Vec<SeparatorStats> {
+        self.scip
+            .separator_stats()
+            .into_iter()
+            .map(|(name, n_cuts_applied)| SeparatorStats {
+                name,
+                n_cuts_applied,
+            })
+            .collect()
+    }
+
+    /// Returns the number of solutions found by each primal heuristic.
+    pub fn heuristic_stats(&self) -> Vec<HeuristicStats> {
+        self.scip
+            .heuristic_stats()
+            .into_iter()
+            .map(|(name, n_sols_found, n_best_sols_found)| HeuristicStats {
+                name,
+                n_sols_found,
+                n_best_sols_found,
+            })
+            .collect()
+    }
+
+    /// Returns the detailed solving statistics of the optimization model.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the statistics of the transformed problem are not available, e.g. because the solving
+    /// process was stopped before the problem was transformed.
+    pub fn statistics(&self) -> Statistics {
+        self.try_statistics()
+            .expect("Failed to get the solving statistics")
+    }
+
+    /// Fallible version of [`statistics`](Model::statistics), returns an [`Error`] instead of panicking.
+    pub fn try_statistics(&self) -> Result<Statistics, Error> {
+        self.scip.check_stage("statistics", None, STATS_STAGES)?;
+        Ok(Statistics {
+            status: self.status(),
+            primal_bound: self.obj_val(),
+            dual_bound: self.best_bound(),
+            gap: self.gap(),
+            primal_dual_integral: self.primal_dual_integral(),
+            root_dual_bound: self.root_dual_bound(),
+            root_lp_time: self.root_lp_time(),
+            presolving_time: self.presolving_time(),
+            solving_time: self.solving_time(),
+            n_nodes: self.n_nodes(),
+            n_lp_iterations: self.n_lp_iterations(),
+            max_depth: self.max_depth(),
+            n_restarts: self.n_restarts(),
+            n_sols_found: self.n_sols_found(),
+            n_cuts_applied: self.scip.n_cuts_applied(),
+            separators: self.separator_stats(),
+            heuristics: self.heuristic_stats(),
+        })
+    }
+
+    /// Returns the output of SCIP's `SCIPprintStatistics` as a string.
+    ///
+    /// The output is captured without being passed to the message handler of the model.
+    pub fn statistics_string(&self) -> Result<String, Error> {
+        self.scip
+            .try_call("statistics_string", None, |scip| scip.statistics_string())
+    }
+
+    /// Returns the dual values of the constraints in the final LP, in the order of [`conss`](ModelWithProblem::conss).
+    ///
+    /// The dual solution is only available for pure LPs (without integer variables) that were solved to optimality
+    /// without presolving, e.g. through `set_presolving(ParamSetting::Off)`. Otherwise an `InvalidCall` error is
+    /// returned. The reduced costs of the variables are available through [`lp_redcosts`](Model::lp_redcosts).
+    pub fn lp_duals(&self) -> Result<Vec<f64>, Error> {
+        self.scip.check_stage("lp_duals", None, SOLVED_STAGES)?;
+        if !self.scip.is_dual_sol_available() {
+            return Err(
+                Error::new(Retcode::InvalidCall, "lp_duals", None, self.stage()).with_message(
+                    "the dual solution is only available for pure LPs solved without presolving",
+                ),
+            );
+        }
+        self.scip.try_call("lp_duals", None, |scip| scip.lp_duals())
+    }
+
+    /// Returns the reduced costs of the variables in the final LP, in the order of
+    /// [`vars`](ModelWithProblem::vars).
+    ///
+    /// Like the dual values, the reduced costs are only available for pure LPs (without integer variables) that were
+    /// solved to optimality without presolving, otherwise an `InvalidCall` error is returned.
+    pub fn lp_redcosts(&self) -> Result<Vec<f64>, Error> {
+        self.scip.check_stage("lp_redcosts", None, SOLVED_STAGES)?;
+        if !self.scip.is_dual_sol_available() {
+            return Err(
+                Error::new(Retcode::InvalidCall, "lp_redcosts", None, self.stage()).with_message(
+                    "the reduced costs are only available for pure LPs solved without presolving",
+                ),
+            );
+        }
+        Ok(self.scip.lp_redcosts())
+    }
+
+    /// Computes an irreducible infeasible subset (IIS) of the constraints and variable bounds of an infeasible model,
+    /// e.g. to find out which rules of a planning problem clash, see [`Iis`].
+    ///
+    /// The IIS is found by a deletion filter: each constraint and bound is removed in turn from a copy of the
+    /// original problem, and stays removed if the copy is still infeasible. This solves the problem once per
+    /// constraint and finite bound, with the parameters of the model, so it can be expensive for large models.
+    ///
+    /// Returns an `InvalidCall` error if the model was not proven infeasible, and an `InvalidData` error if the
+    /// problem cannot be copied completely, e.g. because a constraint handler does not support copying.
+    pub fn compute_iis(&self) -> Result<Iis, Error> {
+        self.scip.check_stage("compute_iis", None, SOLVED_STAGES)?;
+        if self.status() != Status::Infeasible {
+            return Err(
+                Error::new(Retcode::InvalidCall, "compute_iis", None, self.stage())
+                    .with_message("an IIS can only be computed for infeasible models"),
+            );
+        }
+        let (conss, bounds) = self.scip.try_call("compute_iis", None, deletion_filter)?;
+        Ok(Iis {
+            conss: conss
+                .into_iter()
+                .map(|raw| Constraint {
+                    raw,
+                    scip: self.scip.clone(),
+                })
+                .collect(),
+            bounds: bounds
+                .into_iter()
+                .map(|(raw, bound)| {
+                    let var = Variable {
+                        raw,
+                        scip: self.scip.clone(),
+                    };
+                    (var, bound)
+                })
+                .collect(),
+        })
+    }
+
     /// Frees the transformed problem and returns the model the ProblemCreated state where you
     /// can add variables and constraints, useful for iterated solving
     pub fn free_transform(self) -> Model<ProblemCreated> {
+        self.try_free_transform()
+            .unwrap_or_else(|err| panic!("SCIP returned unexpected retcode {:?}", err.retcode()))
+    }
+
+    /// Fallible version of [`free_transform`](Model::free_transform), returns an [`Error`] instead of panicking.
+    pub fn try_free_transform(self) -> Result<Model<ProblemCreated>, Error> {
         self.scip
-            .free_transform()
-            .unwrap_or_else(|retcode| panic!("SCIP returned unexpected retcode {:?}", retcode));
-        Model {
+            .try_call("free_transform", None, |scip| scip.free_transform())?;
+        Ok(Model {
             scip: self.scip,
             state: ProblemCreated {},
-        }
+        })
     }
 }
 
@@ -539,7 +1645,21 @@ pub trait ModelWithProblem {
     fn conss(&self) -> Vec<Constraint>;
 
     /// Writes the optimization model to a file with the given path and extension.
-    fn write(&self, path: &str, ext: &str) -> Result<(), Retcode>;
+    fn write(&self, path: &str, ext: &str) -> Result<(), Error>;
+
+    /// Copies the current problem, i.e., the presolved problem with its global bounds once solving started, together
+    /// with the plugins and parameters into a new model, e.g. to solve a sub-problem in a large neighbourhood search
+    /// heuristic (which should then be included with `usessubscip` set). Plugins implemented in Rust are not copied.
+    ///
+    /// Returns the copy and a map from the ids of the variables of this model to their copies. Returns an
+    /// `InvalidData` error if the problem could not be copied completely.
+    fn copy(&self) -> Result<(Model<ProblemCreated>, HashMap<VarId, Variable>), Error>;
+
+    /// Copies the original problem, together with the plugins and parameters, into a new model, see
+    /// [`copy`](ModelWithProblem::copy).
+    ///
+    /// Returns the copy and a map from the ids of the original variables to their copies.
+    fn copy_orig(&self) -> Result<(Model<ProblemCreated>, HashMap<VarId, Variable>), Error>;
 }
 
 /// A trait for model stages that have a problem.
@@ -599,9 +1719,17 @@ impl<S: ModelStageWithProblem> ModelWithProblem for Model<S> {
     }
 
     /// Writes the optimization model to a file with the given path and extension.
-    fn write(&self, path: &str, ext: &str) -> Result<(), Retcode> {
-        self.scip.write(path, ext)?;
-        Ok(())
+    fn write(&self, path: &str, ext: &str) -> Result<(), Error> {
+        self.scip
+            .try_call("write", Some(path), |scip| scip.write(path, ext))
+    }
+
+    fn copy(&self) -> Result<(Model<ProblemCreated>, HashMap<VarId, Variable>), Error> {
+        self.copy_model("copy", false)
+    }
+
+    fn copy_orig(&self) -> Result<(Model<ProblemCreated>, HashMap<VarId, Variable>), Error> {
+        self.copy_model("copy_orig", true)
     }
 }
 
@@ -794,6 +1922,96 @@ pub trait ProblemOrSolving {
         rhs: f64,
         name: &str,
     ) -> Constraint;
+
+    /// Fallible version of [`create_sol`](ProblemOrSolving::create_sol).
+    fn try_create_sol(&self) -> Result<Solution, Error>;
+
+    /// Fallible version of [`add_sol`](ProblemOrSolving::add_sol).
+    ///
+    /// # Returns
+    /// Whether the solution was stored, i.e. it is feasible and good enough to be kept.
+    fn try_add_sol(&self, sol: Solution) -> Result<bool, Error>;
+
+    /// Fallible version of [`add_cons_coef_setppc`](ProblemOrSolving::add_cons_coef_setppc), returns an
+    /// `InvalidData` error if the variable is not binary.
+    fn try_add_cons_coef_setppc(&mut self, cons: &Constraint, var: &Variable) -> Result<(), Error>;
+
+    /// Fallible version of [`add_cons_coef`](ProblemOrSolving::add_cons_coef).
+    fn try_add_cons_coef(
+        &mut self,
+        cons: &Constraint,
+        var: &Variable,
+        coef: f64,
+    ) -> Result<(), Error>;
+
+    /// Fallible version of [`add_cons_quadratic`](ProblemOrSolving::add_cons_quadratic), returns an
+    /// `InvalidData` error if the lengths of the variables and coefficients do not match.
+    fn try_add_cons_quadratic(
+        &mut self,
+        lin_vars: Vec<&Variable>,
+        lin_coefs: &mut [f64],
+        quad_vars_1: Vec<&Variable>,
+        quad_vars_2: Vec<&Variable>,
+        quad_coefs: &mut [f64],
+        lhs: f64,
+        rhs: f64,
+        name: &str,
+    ) -> Result<Constraint, Error>;
+
+    /// Fallible version of [`add_cons`](ProblemOrSolving::add_cons), returns an `InvalidData` error if the
+    /// lengths of the variables and coefficients do not match.
+    fn try_add_cons(
+        &mut self,
+        vars: Vec<&Variable>,
+        coefs: &[f64],
+        lhs: f64,
+        rhs: f64,
+        name: &str,
+    ) -> Result<Constraint, Error>;
+
+    /// Fallible version of [`add_cons_set_part`](ProblemOrSolving::add_cons_set_part), returns an `InvalidData`
+    /// error if any of the variables is not binary.
+    fn try_add_cons_set_part(
+        &mut self,
+        vars: Vec<&Variable>,
+        name: &str,
+    ) -> Result<Constraint, Error>;
+
+    /// Fallible version of [`add_cons_set_cover`](ProblemOrSolving::add_cons_set_cover), returns an
+    /// `InvalidData` error if any of the variables is not binary.
+    fn try_add_cons_set_cover(
+        &mut self,
+        vars: Vec<&Variable>,
+        name: &str,
+    ) -> Result<Constraint, Error>;
+
+    /// Fallible version of [`add_cons_set_pack`](ProblemOrSolving::add_cons_set_pack), returns an `InvalidData`
+    /// error if any of the variables is not binary.
+    fn try_add_cons_set_pack(
+        &mut self,
+        vars: Vec<&Variable>,
+        name: &str,
+    ) -> Result<Constraint, Error>;
+
+    /// Fallible version of [`add_cons_cardinality`](ProblemOrSolving::add_cons_cardinality).
+    fn try_add_cons_cardinality(
+        &mut self,
+        vars: Vec<&Variable>,
+        cardinality: usize,
+        name: &str,
+    ) -> Result<Constraint, Error>;
+
+    /// Fallible version of [`add_cons_indicator`](ProblemOrSolving::add_cons_indicator), returns an
+    /// `InvalidData` error if the binary variable is not binary or the lengths of the variables and coefficients
+    /// do not match.
+    fn try_add_cons_indicator(
+        &mut self,
+        bin_var: &Variable,
+        vars: Vec<&Variable>,
+        coefs: &mut [f64],
+        rhs: f64,
+        name: &str,
+    ) -> Result<Constraint, Error>;
 }
 
 /// A trait for model stages that have a problem or are during solving.
@@ -804,14 +2022,8 @@ impl ModelStageProblemOrSolving for Solving {}
 impl<S: ModelStageProblemOrSolving> ProblemOrSolving for Model<S> {
     /// Creates a new solution initialized to zero.
     fn create_sol(&self) -> Solution {
-        let sol_ptr = self
-            .scip
-            .create_sol()
-            .expect("Failed to create solution in state ProblemCreated");
-        Solution {
-            scip_ptr: self.scip.clone(),
-            raw: sol_ptr,
-        }
+        self.try_create_sol()
+            .expect("Failed to create solution in state ProblemCreated")
     }
 
     /// Adds a solution to the model
@@ -819,7 +2031,7 @@ impl<S: ModelStageProblemOrSolving> ProblemOrSolving for Model<S> {
     /// # Returns
     /// A `Result` indicating whether the solution was added successfully.
     fn add_sol(&self, sol: Solution) -> Result<(), SolError> {
-        let succesfully_stored = self.scip.add_sol(sol).expect("Failed to add solution");
+        let succesfully_stored = self.try_add_sol(sol).expect("Failed to add solution");
         if succesfully_stored {
             Ok(())
         } else {
@@ -838,9 +2050,7 @@ impl<S: ModelStageProblemOrSolving> ProblemOrSolving for Model<S> {
     ///
     /// This method panics if the variable cannot be added in the current state, or if the variable is not binary.
     fn add_cons_coef_setppc(&mut self, cons: &Constraint, var: &Variable) {
-        assert_eq!(var.var_type(), VarType::Binary);
-        self.scip
-            .add_cons_coef_setppc(cons, var)
+        self.try_add_cons_coef_setppc(cons, var)
             .expect("Failed to add constraint coefficient in state ProblemCreated");
     }
 
@@ -856,8 +2066,7 @@ impl<S: ModelStageProblemOrSolving> ProblemOrSolving for Model<S> {
     ///
     /// This method panics if the coefficient cannot be added in the current state.
     fn add_cons_coef(&mut self, cons: &Constraint, var: &Variable, coef: f64) {
-        self.scip
-            .add_cons_coef(cons, var, coef)
+        self.try_add_cons_coef(cons, var, coef)
             .expect("Failed to add constraint coefficient in state ProblemCreated");
     }
 
@@ -892,27 +2101,17 @@ impl<S: ModelStageProblemOrSolving> ProblemOrSolving for Model<S> {
         rhs: f64,
         name: &str,
     ) -> Constraint {
-        assert_eq!(lin_vars.len(), lin_coefs.len());
-        assert_eq!(quad_vars_1.len(), quad_vars_2.len());
-        assert_eq!(quad_vars_1.len(), quad_coefs.len());
-        let cons = self
-            .scip
-            .create_cons_quadratic(
-                lin_vars,
-                lin_coefs,
-                quad_vars_1,
-                quad_vars_2,
-                quad_coefs,
-                lhs,
-                rhs,
-                name,
-            )
-            .expect("Failed to create constraint in state ProblemCreated");
-
-        Constraint {
-            raw: cons,
-            scip: self.scip.clone(),
-        }
+        self.try_add_cons_quadratic(
+            lin_vars,
+            lin_coefs,
+            quad_vars_1,
+            quad_vars_2,
+            quad_coefs,
+            lhs,
+            rhs,
+            name,
+        )
+        .expect("Failed to create constraint in state ProblemCreated")
     }
 
     /// Adds a new constraint to the model with the given variables, coefficients, left-hand side, right-hand side, and name.
@@ -940,16 +2139,8 @@ impl<S: ModelStageProblemOrSolving> ProblemOrSolving for Model<S> {
         rhs: f64,
         name: &str,
     ) -> Constraint {
-        assert_eq!(vars.len(), coefs.len());
-        let cons = self
-            .scip
-            .create_cons(vars, coefs, lhs, rhs, name)
-            .expect("Failed to create constraint in state ProblemCreated");
-
-        Constraint {
-            raw: cons,
-            scip: self.scip.clone(),
-        }
+        self.try_add_cons(vars, coefs, lhs, rhs, name)
+            .expect("Failed to create constraint in state ProblemCreated")
     }
 
     /// Adds a new set partitioning constraint to the model with the given variables and name.
@@ -967,16 +2158,8 @@ impl<S: ModelStageProblemOrSolving> ProblemOrSolving for Model<S> {
     ///
     /// This method panics if the constraint cannot be created in the current state, or if any of the variables are not binary.
     fn add_cons_set_part(&mut self, vars: Vec<&Variable>, name: &str) -> Constraint {
-        assert!(vars.iter().all(|v| v.var_type() == VarType::Binary));
-        let cons = self
-            .scip
-            .create_cons_set_part(vars, name)
-            .expect("Failed to add constraint set partition in state ProblemCreated");
-
-        Constraint {
-            raw: cons,
-            scip: self.scip.clone(),
-        }
+        self.try_add_cons_set_part(vars, name)
+            .expect("Failed to add constraint set partition in state ProblemCreated")
     }
 
     /// Adds a new set cover constraint to the model with the given variables and name.
@@ -994,16 +2177,8 @@ impl<S: ModelStageProblemOrSolving> ProblemOrSolving for Model<S> {
     ///
     /// This method panics if the constraint cannot be created in the current state, or if any of the variables are not binary.
     fn add_cons_set_cover(&mut self, vars: Vec<&Variable>, name: &str) -> Constraint {
-        assert!(vars.iter().all(|v| v.var_type() == VarType::Binary));
-        let cons = self
-            .scip
-            .create_cons_set_cover(vars, name)
-            .expect("Failed to add constraint set cover in state ProblemCreated");
-
-        Constraint {
-            raw: cons,
-            scip: self.scip.clone(),
-        }
+        self.try_add_cons_set_cover(vars, name)
+            .expect("Failed to add constraint set cover in state ProblemCreated")
     }
 
     /// Adds a new set packing constraint to the model with the given variables and name.
@@ -1021,16 +2196,8 @@ impl<S: ModelStageProblemOrSolving> ProblemOrSolving for Model<S> {
     ///
     /// This method panics if the constraint cannot be created in the current state, or if any of the variables are not binary.
     fn add_cons_set_pack(&mut self, vars: Vec<&Variable>, name: &str) -> Constraint {
-        assert!(vars.iter().all(|v| v.var_type() == VarType::Binary));
-        let cons = self
-            .scip
-            .create_cons_set_pack(vars, name)
-            .expect("Failed to add constraint set packing in state ProblemCreated");
-
-        Constraint {
-            raw: cons,
-            scip: self.scip.clone(),
-        }
+        self.try_add_cons_set_pack(vars, name)
+            .expect("Failed to add constraint set packing in state ProblemCreated")
     }
 
     /// Adds a new cardinality constraint to the model with the given variables, cardinality limit, and name.
@@ -1054,15 +2221,8 @@ impl<S: ModelStageProblemOrSolving> ProblemOrSolving for Model<S> {
         cardinality: usize,
         name: &str,
     ) -> Constraint {
-        let cons = self
-            .scip
-            .create_cons_cardinality(vars, cardinality, name)
-            .expect("Failed to add cardinality constraint");
-
-        Constraint {
-            raw: cons,
-            scip: self.scip.clone(),
-        }
+        self.try_add_cons_cardinality(vars, cardinality, name)
+            .expect("Failed to add cardinality constraint")
     }
 
     /// Adds a new indicator constraint to the model with the given variables, coefficients, right-hand side, and name.
@@ -1090,16 +2250,305 @@ impl<S: ModelStageProblemOrSolving> ProblemOrSolving for Model<S> {
         rhs: f64,
         name: &str,
     ) -> Constraint {
-        assert_eq!(vars.len(), coefs.len());
-        assert_eq!(bin_var.var_type(), VarType::Binary);
+        self.try_add_cons_indicator(bin_var, vars, coefs, rhs, name)
+            .expect("Failed to create constraint in state ProblemCreated")
+    }
+
+    fn try_create_sol(&self) -> Result<Solution, Error> {
+        self.scip
+            .check_stage("create_sol", None, CREATE_SOL_STAGES)?;
+        let sol_ptr = self
+            .scip
+            .try_call("create_sol", None, |scip| scip.create_sol())?;
+        Ok(Solution {
+            scip_ptr: self.scip.clone(),
+            raw: sol_ptr,
+        })
+    }
+
+    fn try_add_sol(&self, sol: Solution) -> Result<bool, Error> {
+        self.scip.check_stage("add_sol", None, ADD_SOL_STAGES)?;
+        self.scip
+            .try_call("add_sol", None, |scip| scip.add_sol(sol))
+    }
+
+    fn try_add_cons_coef_setppc(&mut self, cons: &Constraint, var: &Variable) -> Result<(), Error> {
+        let name = var.name();
+        self.check_binary("add_cons_coef_setppc", [var])?;
+        self.scip
+            .try_call("add_cons_coef_setppc", Some(&name), |scip| {
+                scip.add_cons_coef_setppc(cons, var)
+            })
+    }
+
+    fn try_add_cons_coef(
+        &mut self,
+        cons: &Constraint,
+        var: &Variable,
+        coef: f64,
+    ) -> Result<(), Error> {
+        self.scip
+            .try_call("add_cons_coef", Some(&var.name()), |scip| {
+                scip.add_cons_coef(cons, var, coef)
+            })
+    }
+
+    fn try_add_cons_quadratic(
+        &mut self,
+        lin_vars: Vec<&Variable>,
+        lin_coefs: &mut [f64],
+        quad_vars_1: Vec<&Variable>,
+        quad_vars_2: Vec<&Variable>,
+        quad_coefs: &mut [f64],
+        lhs: f64,
+        rhs: f64,
+        name: &str,
+    ) -> Result<Constraint, Error> {
+        self.scip
+            .check_stage("add_cons_quadratic", Some(name), ADD_CONS_STAGES)?;
+        if lin_vars.len() != lin_coefs.len()
+            || quad_vars_1.len() != quad_vars_2.len()
+            || quad_vars_1.len() != quad_coefs.len()
+        {
+            return Err(self.invalid_data("add_cons_quadratic", name));
+        }
+        let cons = self
+            .scip
+            .try_call("add_cons_quadratic", Some(name), |scip| {
+                scip.create_cons_quadratic(
+                    lin_vars,
+                    lin_coefs,
+                    quad_vars_1,
+                    quad_vars_2,
+                    quad_coefs,
+                    lhs,
+                    rhs,
+                    name,
+                )
+            })?;
+
+        Ok(Constraint {
+            raw: cons,
+            scip: self.scip.clone(),
+        })
+    }
+
+    fn try_add_cons(
+        &mut self,
+        vars: Vec<&Variable>,
+        coefs: &[f64],
+        lhs: f64,
+        rhs: f64,
+        name: &str,
+    ) -> Result<Constraint, Error> {
+        self.scip
+            .check_stage("add_cons", Some(name), ADD_CONS_STAGES)?;
+        if vars.len() != coefs.len() {
+            return Err(self.invalid_data("add_cons", name));
+        }
+        let cons = self.scip.try_call("add_cons", Some(name), |scip| {
+            scip.create_cons(vars, coefs, lhs, rhs, name)
+        })?;
+
+        Ok(Constraint {
+            raw: cons,
+            scip: self.scip.clone(),
+        })
+    }
+
+    fn try_add_cons_set_part(
+        &mut self,
+        vars: Vec<&Variable>,
+        name: &str,
+    ) -> Result<Constraint, Error> {
+        self.scip
+            .check_stage("add_cons_set_part", Some(name), ADD_CONS_STAGES)?;
+        self.check_binary("add_cons_set_part", vars.iter().copied())?;
+        let cons = self
+            .scip
+            .try_call("add_cons_set_part", Some(name), |scip| {
+                scip.create_cons_set_part(vars, name)
+            })?;
+
+        Ok(Constraint {
+            raw: cons,
+            scip: self.scip.clone(),
+        })
+    }
+
+    fn try_add_cons_set_cover(
+        &mut self,
+        vars: Vec<&Variable>,
+        name: &str,
+    ) -> Result<Constraint, Error> {
+        self.scip
+            .check_stage("add_cons_set_cover", Some(name), ADD_CONS_STAGES)?;
+        self.check_binary("add_cons_set_cover", vars.iter().copied())?;
+        let cons = self
+            .scip
+            .try_call("add_cons_set_cover", Some(name), |scip| {
+                scip.create_cons_set_cover(vars, name)
+            })?;
+
+        Ok(Constraint {
+            raw: cons,
+            scip: self.scip.clone(),
+        })
+    }
+
+    fn try_add_cons_set_pack(
+        &mut self,
+        vars: Vec<&Variable>,
+        name: &str,
+    ) -> Result<Constraint, Error> {
+        self.scip
+            .check_stage("add_cons_set_pack", Some(name), ADD_CONS_STAGES)?;
+        self.check_binary("add_cons_set_pack", vars.iter().copied())?;
+        let cons = self
+            .scip
+            .try_call("add_cons_set_pack", Some(name), |scip| {
+                scip.create_cons_set_pack(vars, name)
+            })?;
+
+        Ok(Constraint {
+            raw: cons,
+            scip: self.scip.clone(),
+        })
+    }
+
+    fn try_add_cons_cardinality(
+        &mut self,
+        vars: Vec<&Variable>,
+        cardinality: usize,
+        name: &str,
+    ) -> Result<Constraint, Error> {
+        self.scip
+            .check_stage("add_cons_cardinality", Some(name), ADD_CONS_STAGES)?;
+        let cons = self
+            .scip
+            .try_call("add_cons_cardinality", Some(name), |scip| {
+                scip.create_cons_cardinality(vars, cardinality, name)
+            })?;
+
+        Ok(Constraint {
+            raw: cons,
+            scip: self.scip.clone(),
+        })
+    }
+
+    fn try_add_cons_indicator(
+        &mut self,
+        bin_var: &Variable,
+        vars: Vec<&Variable>,
+        coefs: &mut [f64],
+        rhs: f64,
+        name: &str,
+    ) -> Result<Constraint, Error> {
+        self.scip
+            .check_stage("add_cons_indicator", Some(name), ADD_CONS_STAGES)?;
+        if vars.len() != coefs.len() {
+            return Err(self.invalid_data("add_cons_indicator", name));
+        }
+        self.check_binary("add_cons_indicator", [bin_var])?;
         let cons = self
             .scip
-            .create_cons_indicator(bin_var, vars, coefs, rhs, name)
-            .expect("Failed to create constraint in state ProblemCreated");
+            .try_call("add_cons_indicator", Some(name), |scip| {
+                scip.create_cons_indicator(bin_var, vars, coefs, rhs, name)
+            })?;
 
-        Constraint {
+        Ok(Constraint {
             raw: cons,
             scip: self.scip.clone(),
+        })
+    }
+}
+
+impl<S> Model<S> {
+    fn copy_model(
+        &self,
+        operation: &'static str,
+        original: bool,
+    ) -> Result<(Model<ProblemCreated>, HashMap<VarId, Variable>), Error> {
+        let copy = self
+            .scip
+            .try_call(operation, None, |scip| scip.copy(original, true))?;
+        if !copy.valid {
+            return Err(
+                Error::new(Retcode::InvalidData, operation, None, self.stage())
+                    .with_message("the problem could not be copied completely"),
+            );
+        }
+        let scip = Rc::new(copy.scip);
+        let vars = copy
+            .vars
+            .into_iter()
+            .map(|(source, target)| {
+                let var_id = unsafe { ffi::SCIPvarGetIndex(source) } as VarId;
+                let var = Variable {
+                    raw: target,
+                    scip: scip.clone(),
+                };
+                (var_id, var)
+            })
+            .collect();
+        let model = Model {
+            scip,
+            state: ProblemCreated {},
+        };
+        Ok((model, vars))
+    }
+
+    /// Wraps the model into a [`SendModel`], which can be moved to another thread.
+    ///
+    /// This requires that no variables, constraints, solutions or other handles of the model are alive anymore, and
+    /// that no message handler or custom plugins were included, except for plugins included through the `_send`
+    /// variants such as [`include_heur_send`](Model::include_heur_send). Otherwise an `InvalidCall` error is
+    /// returned.
+    pub fn into_send(self) -> Result<SendModel<S>, Error> {
+        self.check_sendable("into_send")?;
+        Ok(SendModel(self))
+    }
+
+    /// Returns an `InvalidCall` error unless the model is the only owner of its SCIP instance and holds nothing that
+    /// is bound to the current thread, so that it can be moved to another thread.
+    fn check_sendable(&self, operation: &'static str) -> Result<(), Error> {
+        if self.scip.weak
+            || Rc::strong_count(&self.scip) > 1
+            || Rc::weak_count(&self.scip) > 0
+            || self.scip.has_shared_benders_subproblems()
+            || self.scip.thread_bound.get()
+        {
+            return Err(
+                Error::new(Retcode::InvalidCall, operation, None, self.stage()).with_message(
+                    &format!(
+                        "{operation} requires that no variables, constraints, solutions, custom plugins or \
+                         message handler of the model or its Benders' subproblems are alive"
+                    ),
+                ),
+            );
+        }
+        Ok(())
+    }
+
+    /// Returns an `InvalidData` error for the given operation, used for invalid arguments detected before calling SCIP.
+    fn invalid_data(&self, operation: &'static str, name: &str) -> Error {
+        Error::new(
+            Retcode::InvalidData,
+            operation,
+            Some(name),
+            self.scip.stage(),
+        )
+    }
+
+    /// Returns an `InvalidData` error naming the first of the given variables that is not binary.
+    fn check_binary<'a>(
+        &self,
+        operation: &'static str,
+        vars: impl IntoIterator<Item = &'a Variable>,
+    ) -> Result<(), Error> {
+        match vars.into_iter().find(|v| v.var_type() != VarType::Binary) {
+            Some(var) => Err(self.invalid_data(operation, &var.name())),
+            None => Ok(()),
         }
     }
 }
@@ -1146,6 +2595,9 @@ pub trait WithSolvingStats {
     /// Returns the best bound (dualbound) proven so far.
     fn best_bound(&self) -> f64;
 
+    /// Returns the relative gap between the primal and the dual bound.
+    fn gap(&self) -> f64;
+
     /// Returns the number of nodes explored by the optimization model.
     fn n_nodes(&self) -> usize;
 
@@ -1154,6 +2606,9 @@ pub trait WithSolvingStats {
 
     /// Returns the number of LP iterations performed by the optimization model.
     fn n_lp_iterations(&self) -> usize;
+
+    /// Returns a summary of the solving statistics of the optimization model.
+    fn summary(&self) -> SolveSummary;
 }
 
 trait ModelStageWithSolvingStats {}
@@ -1172,6 +2627,11 @@ impl<S: ModelStageWithSolvingStats> WithSolvingStats for Model<S> {
         self.scip.best_bound()
     }
 
+    /// Returns the relative gap between the primal and the dual bound.
+    fn gap(&self) -> f64 {
+        self.scip.gap()
+    }
+
     /// Returns the number of nodes explored by the optimization model.
     fn n_nodes(&self) -> usize {
         self.scip.n_nodes()
@@ -1186,6 +2646,19 @@ impl<S: ModelStageWithSolvingStats> WithSolvingStats for Model<S> {
     fn n_lp_iterations(&self) -> usize {
         self.scip.n_lp_iterations()
     }
+
+    /// Returns a summary of the solving statistics of the optimization model.
+    fn summary(&self) -> SolveSummary {
+        SolveSummary {
+            status: self.status(),
+            obj_val: self.obj_val(),
+            best_bound: self.best_bound(),
+            gap: self.gap(),
+            n_nodes: self.n_nodes(),
+            solving_time: self.solving_time(),
+            n_lp_iterations: self.n_lp_iterations(),
+        }
+    }
 }
 
 /// Creates a minimal `Model` instance and sets off a lot of SCIP plugins, useful for writing tests.
@@ -1207,6 +2680,14 @@ impl<T> Model<T> {
         self.scip.raw
     }
 
+    /// Returns the current stage of the SCIP instance.
+    ///
+    /// This is finer grained than the state of the model, e.g. plugins are called with a `Model<Solving>` both
+    /// during presolving and solving.
+    pub fn stage(&self) -> Stage {
+        self.scip.stage()
+    }
+
     /// Returns the status of the optimization model.
     pub fn status(&self) -> Status {
         self.scip.status()
@@ -1238,6 +2719,30 @@ impl<T> Model<T> {
         self.set_display_verbosity(0)
     }
 
+    /// Routes SCIP's output of this model to the given message handler instead of stdout.
+    ///
+    /// The handler can be any type implementing [`MessageHandler`], e.g. a closure taking a
+    /// [`MessageLevel`](crate::MessageLevel) and the message line. With the `log` or `tracing` features enabled, `LogMessageHandler` and
+    /// `TracingMessageHandler` forward the output to the respective crate.
+    #[allow(unused_mut)]
+    pub fn set_message_handler<H: MessageHandler + 'static>(mut self, handler: H) -> Self {
+        self.try_set_message_handler(handler)
+            .expect("Failed to set message handler")
+    }
+
+    /// Fallible version of [`set_message_handler`](Model::set_message_handler), returns an [`Error`] instead of
+    /// panicking.
+    pub fn try_set_message_handler<H: MessageHandler + 'static>(
+        self,
+        handler: H,
+    ) -> Result<Self, Error> {
+        self.scip.thread_bound.set(true);
+        self.scip.try_call("set_message_handler", None, |scip| {
+            scip.set_message_handler(Box::new(handler))
+        })?;
+        Ok(self)
+    }
+
     /// Sets the time limit for the optimization model.
     ///
     /// # Arguments
@@ -1245,10 +2750,13 @@ impl<T> Model<T> {
     /// * `time_limit` - The time limit in seconds.
     #[allow(unused_mut)]
     pub fn set_time_limit(mut self, time_limit: usize) -> Self {
-        self.scip
-            .set_real_param("limits/time", time_limit as f64)
-            .expect("Failed to set time limit");
-        self
+        self.try_set_time_limit(time_limit)
+            .expect("Failed to set time limit")
+    }
+
+    /// Fallible version of [`set_time_limit`](Model::set_time_limit), returns an [`Error`] instead of panicking.
+    pub fn try_set_time_limit(self, time_limit: usize) -> Result<Self, Error> {
+        self.set_real_param("limits/time", time_limit as f64)
     }
 
     /// Sets the memory limit for the optimization model.
@@ -1258,56 +2766,87 @@ impl<T> Model<T> {
     /// * `memory_limit` - The memory limit in MB.
     #[allow(unused_mut)]
     pub fn set_memory_limit(mut self, memory_limit: usize) -> Self {
-        self.scip
-            .set_real_param("limits/memory", memory_limit as f64)
-            .expect("Failed to set memory limit");
-        self
+        self.try_set_memory_limit(memory_limit)
+            .expect("Failed to set memory limit")
+    }
+
+    /// Fallible version of [`set_memory_limit`](Model::set_memory_limit), returns an [`Error`] instead of panicking.
+    pub fn try_set_memory_limit(self, memory_limit: usize) -> Result<Self, Error> {
+        self.set_real_param("limits/memory", memory_limit as f64)
     }
 
     /// Includes all default plugins in the SCIP instance and returns a new `Model` instance with a `PluginsIncluded` state.
     #[allow(unused_mut)]
     pub fn include_default_plugins(mut self) -> Model<PluginsIncluded> {
+        self.try_include_default_plugins()
+            .expect("Failed to include default plugins")
+    }
+
+    /// Fallible version of [`include_default_plugins`](Model::include_default_plugins), returns an [`Error`]
+    /// instead of panicking.
+    pub fn try_include_default_plugins(self) -> Result<Model<PluginsIncluded>, Error> {
         self.scip
-            .include_default_plugins()
-            .expect("Failed to include default plugins");
-        Model {
+            .try_call("include_default_plugins", None, |scip| {
+                scip.include_default_plugins()
+            })?;
+        Ok(Model {
             scip: self.scip,
             state: PluginsIncluded {},
-        }
+        })
     }
 
     /// Sets a SCIP string parameter and returns a new `Model` instance with the parameter set.
     #[allow(unused_mut)]
-    pub fn set_str_param(mut self, param: &str, value: &str) -> Result<Self, Retcode> {
-        self.scip.set_str_param(param, value)?;
+    pub fn set_str_param(mut self, param: &str, value: &str) -> Result<Self, Error> {
+        self.scip.try_call("set_str_param", Some(param), |scip| {
+            scip.set_str_param(param, value)
+        })?;
         Ok(self)
     }
 
     /// Sets a SCIP boolean parameter and returns a new `Model` instance with the parameter set.
     #[allow(unused_mut)]
-    pub fn set_bool_param(mut self, param: &str, value: bool) -> Result<Self, Retcode> {
-        self.scip.set_bool_param(param, value)?;
+    pub fn set_bool_param(mut self, param: &str, value: bool) -> Result<Self, Error> {
+        self.scip.try_call("set_bool_param", Some(param), |scip| {
+            scip.set_bool_param(param, value)
+        })?;
+        Ok(self)
+    }
+
+    /// Sets a SCIP character parameter and returns a new `Model` instance with the parameter set.
+    #[allow(unused_mut)]
+    pub fn set_char_param(mut self, param: &str, value: char) -> Result<Self, Error> {
+        self.scip.try_call("set_char_param", Some(param), |scip| {
+            scip.set_char_param(param, value)
+        })?;
         Ok(self)
     }
 
     /// Sets a SCIP integer parameter and returns a new `Model` instance with the parameter set.
     #[allow(unused_mut)]
-    pub fn set_int_param(mut self, param: &str, value: i32) -> Result<Self, Retcode> {
-        self.scip.set_int_param(param, value)?;
+    pub fn set_int_param(mut self, param: &str, value: i32) -> Result<Self, Error> {
+        self.scip.try_call("set_int_param", Some(param), |scip| {
+            scip.set_int_param(param, value)
+        })?;
         Ok(self)
     }
 
     /// Sets a SCIP long integer parameter and returns a new `Model` instance with the parameter set.
     #[allow(unused_mut)]
-    pub fn set_longint_param(mut self, param: &str, value: i64) -> Result<Self, Retcode> {
-        self.scip.set_longint_param(param, value)?;
+    pub fn set_longint_param(mut self, param: &str, value: i64) -> Result<Self, Error> {
+        self.scip
+            .try_call("set_longint_param", Some(param), |scip| {
+                scip.set_longint_param(param, value)
+            })?;
         Ok(self)
     }
 
     /// Sets a SCIP real parameter and returns a new `Model` instance with the parameter set.
     #[allow(unused_mut)]
-    pub fn set_real_param(mut self, param: &str, value: f64) -> Result<Self, Retcode> {
-        self.scip.set_real_param(param, value)?;
+    pub fn set_real_param(mut self, param: &str, value: f64) -> Result<Self, Error> {
+        self.scip.try_call("set_real_param", Some(param), |scip| {
+            scip.set_real_param(param, value)
+        })?;
         Ok(self)
     }
 
@@ -1319,17 +2858,20 @@ impl<T> Model<T> {
             .to_string()
     }
 
+    /// Returns the value of a SCIP character parameter.
+    pub fn char_param(&self, param: &str) -> char {
+        self.scip
+            .char_param(param)
+            .expect("Failed to get character parameter")
+    }
+
     /// Returns the value of a SCIP paramter.
     pub fn param<P: ScipParameter>(&self, param: &str) -> P {
         P::get(self, param)
     }
 
     /// Tries to set the value of a SCIP parameter and returns the same `Model` instance if successful.
-    pub fn try_set_param<P: ScipParameter>(
-        self,
-        param: &str,
-        value: P,
-    ) -> Result<Model<T>, Retcode> {
+    pub fn try_set_param<P: ScipParameter>(self, param: &str, value: P) -> Result<Model<T>, Error> {
         P::set(self, param, value)
     }
 
@@ -1338,6 +2880,39 @@ impl<T> Model<T> {
         P::set(self, param, value).expect("Failed to set parameter")
     }
 
+    /// Returns the value of a parameter from the typed catalogue in [`params`](crate::params).
+    ///
+    /// # Example
+    /// ```rust
+    /// use russcip::prelude::*;
+    /// use russcip::params;
+    ///
+    /// let model = Model::new().set(params::limits::Gap, 0.01);
+    /// assert_eq!(model.get(params::limits::Gap), 0.01);
+    /// ```
+    pub fn get<P: Param>(&self, _param: P) -> P::Value {
+        P::Value::get(self, P::NAME)
+    }
+
+    /// Tries to set the value of a parameter from the typed catalogue in [`params`](crate::params) and returns the
+    /// same `Model` instance if successful.
+    pub fn try_set<P: Param>(
+        self,
+        _param: P,
+        value: impl Into<P::Value>,
+    ) -> Result<Model<T>, Error> {
+        P::Value::set(self, P::NAME, value.into())
+    }
+
+    /// Sets the value of a parameter from the typed catalogue in [`params`](crate::params).
+    ///
+    /// The name and the type of the parameter are checked by the compiler, only the value itself
+    /// (e.g. whether it is in the allowed range) is checked by SCIP.
+    pub fn set<P: Param>(self, param: P, value: impl Into<P::Value>) -> Model<T> {
+        self.try_set(param, value)
+            .unwrap_or_else(|_| panic!("Failed to set parameter {}", P::NAME))
+    }
+
     /// Returns the value of a SCIP boolean parameter.
     pub fn bool_param(&self, param: &str) -> bool {
         self.scip
@@ -1366,31 +2941,121 @@ impl<T> Model<T> {
             .expect("Failed to get real parameter")
     }
 
+    /// Returns the metadata and current value of every SCIP parameter.
+    pub fn params(&self) -> Vec<ParamInfo> {
+        self.scip.params()
+    }
+
+    /// Returns the parameters whose current value differs from their default value.
+    pub fn changed_params(&self) -> Vec<ParamInfo> {
+        self.params()
+            .into_iter()
+            .filter(|p| !p.is_default)
+            .collect()
+    }
+
+    /// Resets a SCIP parameter to its default value and returns the same `Model` instance.
+    #[allow(unused_mut)]
+    pub fn reset_param(mut self, param: &str) -> Result<Self, Error> {
+        self.scip
+            .try_call("reset_param", Some(param), |scip| scip.reset_param(param))?;
+        Ok(self)
+    }
+
+    /// Resets all SCIP parameters to their default values and returns the same `Model` instance.
+    #[allow(unused_mut)]
+    pub fn reset_params(mut self) -> Self {
+        self.try_reset_params().expect("Failed to reset parameters")
+    }
+
+    /// Fallible version of [`reset_params`](Model::reset_params), returns an [`Error`] instead of panicking.
+    pub fn try_reset_params(self) -> Result<Self, Error> {
+        self.scip
+            .try_call("reset_params", None, |scip| scip.reset_params())?;
+        Ok(self)
+    }
+
     /// Sets the presolving parameter of the SCIP instance and returns the same `Model` instance.
     #[allow(unused_mut)]
     pub fn set_presolving(mut self, presolving: ParamSetting) -> Self {
-        self.scip
-            .set_presolving(presolving)
-            .expect("Failed to set presolving with valid value");
-        self
+        self.try_set_presolving(presolving)
+            .expect("Failed to set presolving with valid value")
+    }
+
+    /// Fallible version of [`set_presolving`](Model::set_presolving), returns an [`Error`] instead of panicking.
+    pub fn try_set_presolving(self, presolving: ParamSetting) -> Result<Self, Error> {
+        self.scip.try_call("set_presolving", None, |scip| {
+            scip.set_presolving(presolving)
+        })?;
+        Ok(self)
     }
 
     /// Sets the separating parameter of the SCIP instance and returns the same `Model` instance.
     #[allow(unused_mut)]
     pub fn set_separating(mut self, separating: ParamSetting) -> Self {
-        self.scip
-            .set_separating(separating)
-            .expect("Failed to set separating with valid value");
-        self
+        self.try_set_separating(separating)
+            .expect("Failed to set separating with valid value")
+    }
+
+    /// Fallible version of [`set_separating`](Model::set_separating), returns an [`Error`] instead of panicking.
+    pub fn try_set_separating(self, separating: ParamSetting) -> Result<Self, Error> {
+        self.scip.try_call("set_separating", None, |scip| {
+            scip.set_separating(separating)
+        })?;
+        Ok(self)
     }
 
     /// Sets the heuristics parameter of the SCIP instance and returns the same `Model` instance.
     #[allow(unused_mut)]
     pub fn set_heuristics(mut self, heuristics: ParamSetting) -> Self {
+        self.try_set_heuristics(heuristics)
+            .expect("Failed to set heuristics with valid value")
+    }
+
+    /// Fallible version of [`set_heuristics`](Model::set_heuristics), returns an [`Error`] instead of panicking.
+    pub fn try_set_heuristics(self, heuristics: ParamSetting) -> Result<Self, Error> {
+        self.scip.try_call("set_heuristics", None, |scip| {
+            scip.set_heuristics(heuristics)
+        })?;
+        Ok(self)
+    }
+
+    /// Sets the parameters of the SCIP instance to the given emphasis setting and returns the same `Model` instance.
+    #[allow(unused_mut)]
+    pub fn set_emphasis(mut self, emphasis: Emphasis) -> Self {
+        self.try_set_emphasis(emphasis)
+            .expect("Failed to set emphasis with valid value")
+    }
+
+    /// Fallible version of [`set_emphasis`](Model::set_emphasis), returns an [`Error`] instead of panicking.
+    pub fn try_set_emphasis(self, emphasis: Emphasis) -> Result<Self, Error> {
         self.scip
-            .set_heuristics(heuristics)
-            .expect("Failed to set heuristics with valid value");
-        self
+            .try_call("set_emphasis", None, |scip| scip.set_emphasis(emphasis))?;
+        Ok(self)
+    }
+
+    /// Reads SCIP parameters from a settings (`.set`) file and returns the same `Model` instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the settings file.
+    #[allow(unused_mut)]
+    pub fn read_params(mut self, path: &str) -> Result<Self, Error> {
+        self.scip
+            .try_call("read_params", Some(path), |scip| scip.read_params(path))?;
+        Ok(self)
+    }
+
+    /// Writes the SCIP parameters to a settings (`.set`) file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the settings file.
+    /// * `only_changed` - Whether only the parameters that differ from their default value should be written.
+    pub fn write_params(&self, path: &str, only_changed: bool) -> Result<(), Error> {
+        self.scip.try_call("write_params", Some(path), |scip| {
+            scip.write_params(path, only_changed)
+        })
     }
 }
 
@@ -1429,6 +3094,55 @@ impl From<ParamSetting> for ffi::SCIP_PARAMSETTING {
     }
 }
 
+/// An enum representing the emphasis settings of SCIP, i.e. predefined parameter sets for different purposes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Emphasis {
+    /// Use default values.
+    Default,
+    /// Behave like a constraint programming solver.
+    CpSolver,
+    /// Solve easy problems fast.
+    Easycip,
+    /// Detect feasibility fast.
+    Feasibility,
+    /// Be capable to handle hard LPs.
+    HardLp,
+    /// Prove optimality fast.
+    Optimality,
+    /// Get a feasible and "fast" counting process.
+    Counter,
+    /// Find the first feasible solution.
+    PhaseFeas,
+    /// Find improved solutions.
+    PhaseImprove,
+    /// Prove optimality of the best solution.
+    PhaseProof,
+    /// Solve numerically difficult problems with fewer numerical issues.
+    Numerics,
+    /// Use settings suitable for benchmarking (e.g. no memory-dependent behavior).
+    Benchmark,
+}
+
+impl From<Emphasis> for ffi::SCIP_PARAMEMPHASIS {
+    /// Converts an `Emphasis` enum variant into its corresponding `ffi::SCIP_PARAMEMPHASIS` value.
+    fn from(val: Emphasis) -> Self {
+        match val {
+            Emphasis::Default => ffi::SCIP_ParamEmphasis_SCIP_PARAMEMPHASIS_DEFAULT,
+            Emphasis::CpSolver => ffi::SCIP_ParamEmphasis_SCIP_PARAMEMPHASIS_CPSOLVER,
+            Emphasis::Easycip => ffi::SCIP_ParamEmphasis_SCIP_PARAMEMPHASIS_EASYCIP,
+            Emphasis::Feasibility => ffi::SCIP_ParamEmphasis_SCIP_PARAMEMPHASIS_FEASIBILITY,
+            Emphasis::HardLp => ffi::SCIP_ParamEmphasis_SCIP_PARAMEMPHASIS_HARDLP,
+            Emphasis::Optimality => ffi::SCIP_ParamEmphasis_SCIP_PARAMEMPHASIS_OPTIMALITY,
+            Emphasis::Counter => ffi::SCIP_ParamEmphasis_SCIP_PARAMEMPHASIS_COUNTER,
+            Emphasis::PhaseFeas => ffi::SCIP_ParamEmphasis_SCIP_PARAMEMPHASIS_PHASEFEAS,
+            Emphasis::PhaseImprove => ffi::SCIP_ParamEmphasis_SCIP_PARAMEMPHASIS_PHASEIMPROVE,
+            Emphasis::PhaseProof => ffi::SCIP_ParamEmphasis_SCIP_PARAMEMPHASIS_PHASEPROOF,
+            Emphasis::Numerics => ffi::SCIP_ParamEmphasis_SCIP_PARAMEMPHASIS_NUMERICS,
+            Emphasis::Benchmark => ffi::SCIP_ParamEmphasis_SCIP_PARAMEMPHASIS_BENCHMARK,
+        }
+    }
+}
+
 /// An enum representing the objective sense of a SCIP optimization model.
 #[derive(Debug)]
 pub enum ObjSense {
@@ -1450,6 +3164,7 @@ impl From<ObjSense> for ffi::SCIP_OBJSENSE {
 
 #[cfg(test)]
 mod tests {
+    use crate::stage::Stage;
     use crate::status::Status;
     use rayon::prelude::*;
     use std::fs;
@@ -1502,6 +3217,84 @@ mod tests {
         assert_eq!(obj_value, 200.);
     }
 
+    #[test]
+    fn solve_concurrent() {
+        let model = Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .set(crate::params::concurrent::FeasPrefPrio, 1.0)
+            .read_prob("data/test/simple.lp")
+            .unwrap()
+            .solve_concurrent(2);
+        assert_eq!(model.status(), Status::Optimal);
+        assert_eq!(model.obj_val(), 200.);
+        let sol = model.best_sol().unwrap();
+        assert_eq!(sol.obj_val(), 200.);
+    }
+
+    #[test]
+    fn solve_concurrent_without_threads() {
+        let model = Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .read_prob("data/test/simple.lp")
+            .unwrap();
+        let err = model.try_solve_concurrent(0).unwrap_err();
+        assert_eq!(err.retcode(), Retcode::InvalidData);
+    }
+
+    /// Creates a model with the binary variables x, y and z, of which at most one can be set.
+    fn at_most_one_model() -> Model<ProblemCreated> {
+        let mut model = Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .create_prob("at_most_one");
+        let x = model.add(crate::prelude::var().bin().obj(1.0).name("x"));
+        let y = model.add(crate::prelude::var().bin().obj(2.0).name("y"));
+        let z = model.add(crate::prelude::var().bin().obj(3.0).name("z"));
+        model.add(
+            crate::prelude::cons()
+                .coef(&x, 1.0)
+                .coef(&y, 1.0)
+                .coef(&z, 1.0)
+                .le(1.0),
+        );
+        model
+    }
+
+    #[test]
+    fn count_solutions() {
+        let (count, is_exact) = at_most_one_model().count_solutions(None).unwrap();
+        assert_eq!(count, 4);
+        assert!(is_exact);
+    }
+
+    #[test]
+    fn enumerate_solutions() {
+        let sols = at_most_one_model().enumerate_solutions(None).unwrap();
+        assert_eq!(sols.len(), 4);
+        let mut obj_vals: Vec<f64> = sols.iter().map(|sol| sol.obj_val).collect();
+        obj_vals.sort_by(f64::total_cmp);
+        assert_eq!(obj_vals, vec![0.0, 1.0, 2.0, 3.0]);
+        for sol in &sols {
+            let x = sol.val_by_name("x").unwrap();
+            let y = sol.val_by_name("y").unwrap();
+            let z = sol.val_by_name("z").unwrap();
+            assert!(x + y + z <= 1.0);
+        }
+
+        let sols = at_most_one_model().enumerate_solutions(Some(2)).unwrap();
+        assert_eq!(sols.len(), 2);
+    }
+
+    #[test]
+    fn count_solutions_with_continuous_vars() {
+        let mut model = at_most_one_model();
+        model.add(crate::prelude::var().cont(0.0..=1.0).name("w"));
+        let err = model.count_solutions(None).unwrap_err();
+        assert_eq!(err.retcode(), Retcode::InvalidData);
+    }
+
     #[test]
     fn set_time_limit() {
         let model = Model::new()
@@ -1842,6 +3635,315 @@ mod tests {
         assert_eq!(model.status(), Status::Optimal);
     }
 
+    #[test]
+    fn solve_on_other_thread() {
+        let build = || {
+            let mut model = Model::new()
+                .hide_output()
+                .include_default_plugins()
+                .create_prob("test")
+                .maximize();
+            let x = model.add(crate::prelude::var().int(0..=10).obj(1.0).name("x"));
+            model.add(crate::prelude::cons().coef(&x, 2.0).le(7.0));
+            (model, x)
+        };
+
+        // the variable still refers to the SCIP instance
+        let (model, _x) = build();
+        let err = model.into_send().unwrap_err();
+        assert_eq!(err.retcode(), Retcode::InvalidCall);
+
+        let (model, x) = build();
+        drop(x);
+        let model = model.into_send().unwrap();
+        let val = std::thread::spawn(move || {
+            let solved = model.into_inner().solve();
+            solved
+                .best_sol()
+                .unwrap()
+                .to_owned_values()
+                .val_by_name("x")
+        })
+        .join()
+        .unwrap();
+        assert_eq!(val, Some(3.0));
+    }
+
+    struct SendEventhdlr(Arc<std::sync::atomic::AtomicUsize>);
+
+    impl Eventhdlr for SendEventhdlr {
+        fn get_type(&self) -> crate::EventMask {
+            crate::EventMask::NODE_SOLVED
+        }
+
+        fn execute(
+            &mut self,
+            _model: Model<Solving>,
+            _eventhdlr: crate::SCIPEventhdlr,
+            _event: crate::Event,
+        ) {
+            self.0.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        }
+    }
+
+    #[test]
+    fn send_plugins() {
+        let build = || {
+            Model::new()
+                .hide_output()
+                .set_presolving(ParamSetting::Off)
+                .include_default_plugins()
+                .read_prob("data/test/simple.lp")
+                .unwrap()
+        };
+        let n_calls = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let mut model = build();
+        model.include_eventhdlr("thread_bound", "", Box::new(SendEventhdlr(n_calls.clone())));
+        let err = model.into_send().unwrap_err();
+        assert_eq!(err.retcode(), Retcode::InvalidCall);
+
+        let mut model = build();
+        model.include_eventhdlr_send("send", "", Box::new(SendEventhdlr(n_calls.clone())));
+        let model = model.into_send().unwrap();
+        let status = std::thread::spawn(move || model.into_inner().solve().status())
+            .join()
+            .unwrap();
+        assert_eq!(status, Status::Optimal);
+        assert!(n_calls.load(std::sync::atomic::Ordering::Relaxed) > 0);
+    }
+
+    #[test]
+    fn resume_after_limit() {
+        let model = Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .read_prob("data/test/gen-ip054.mps")
+            .unwrap()
+            .set_longint_param("limits/nodes", 1)
+            .unwrap()
+            .solve();
+        assert_eq!(model.status(), Status::NodeLimit);
+        assert!(model.is_resumable());
+        let n_nodes = model.n_nodes();
+
+        let model = model.set_longint_param("limits/nodes", 5).unwrap().solve();
+        assert!(model.n_nodes() > n_nodes);
+
+        let model = model.set_longint_param("limits/nodes", -1).unwrap().solve();
+        assert_eq!(model.status(), Status::Optimal);
+        assert!(!model.is_resumable());
+
+        // solving again is a no-op
+        let n_nodes = model.n_nodes();
+        let model = model.solve();
+        assert_eq!(model.n_nodes(), n_nodes);
+    }
+
+    #[test]
+    fn lp_duals() {
+        let model = Model::new()
+            .hide_output()
+            .set_presolving(ParamSetting::Off)
+            .include_default_plugins()
+            .read_prob("data/test/simple.lp")
+            .unwrap()
+            .solve();
+        assert_eq!(model.status(), Status::Optimal);
+        let duals = model.lp_duals().unwrap();
+        assert_eq!(duals.len(), 2);
+        assert!((duals[0] - 2.0 / 3.0).abs() < 1e-6);
+        assert!((duals[1] - 5.0 / 3.0).abs() < 1e-6);
+
+        let model = Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .read_prob("data/test/gen-ip054.mps")
+            .unwrap()
+            .solve();
+        let err = model.lp_duals().unwrap_err();
+        assert_eq!(err.retcode(), Retcode::InvalidCall);
+    }
+
+    #[test]
+    fn lp_redcosts() {
+        let model = Model::new()
+            .hide_output()
+            .set_presolving(ParamSetting::Off)
+            .include_default_plugins()
+            .read_prob("data/test/simple.lp")
+            .unwrap()
+            .solve();
+        assert_eq!(model.status(), Status::Optimal);
+        // both variables are basic
+        let redcosts = model.lp_redcosts().unwrap();
+        assert_eq!(redcosts.len(), 2);
+        assert!(redcosts.iter().all(|redcost| redcost.abs() < 1e-6));
+
+        // a column using both constraints is too expensive with the dual values (2/3, 5/3)
+        let mut model = Model::new()
+            .hide_output()
+            .set_presolving(ParamSetting::Off)
+            .include_default_plugins()
+            .read_prob("data/test/simple.lp")
+            .unwrap();
+        let x3 = model.add(crate::prelude::var().cont(0.0..).obj(1.0).name("x3"));
+        for cons in model.conss() {
+            model.add_cons_coef(&cons, &x3, 1.0);
+        }
+        let model = model.solve();
+        assert_eq!(model.status(), Status::Optimal);
+        let redcosts = model.lp_redcosts().unwrap();
+        assert_eq!(redcosts.len(), 3);
+        assert!(redcosts[0].abs() < 1e-6);
+        assert!(redcosts[1].abs() < 1e-6);
+        assert!((redcosts[2] + 4.0 / 3.0).abs() < 1e-6);
+
+        let model = Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .read_prob("data/test/gen-ip054.mps")
+            .unwrap()
+            .solve();
+        let err = model.lp_redcosts().unwrap_err();
+        assert_eq!(err.retcode(), Retcode::InvalidCall);
+    }
+
+    #[test]
+    fn stage() {
+        let model = Model::new().hide_output();
+        assert_eq!(model.stage(), Stage::Init);
+        let model = model.include_default_plugins().create_prob("test");
+        assert_eq!(model.stage(), Stage::Problem);
+        let model = model.solve();
+        assert_eq!(model.stage(), Stage::Solved);
+    }
+
+    #[test]
+    fn try_add_cons_errors() {
+        let mut model = Model::default().hide_output();
+        let x = model.add_var(0., 10., 1., "x", VarType::Integer);
+        let y = model.add_var(0., 1., 1., "y", VarType::Binary);
+
+        let err = model
+            .try_add_cons(vec![&x, &y], &[1.], 1., 1., "c1")
+            .unwrap_err();
+        assert_eq!(err.retcode(), Retcode::InvalidData);
+        assert_eq!(err.operation(), "add_cons");
+        assert_eq!(err.name(), Some("c1"));
+        assert_eq!(err.stage(), Stage::Problem);
+
+        let err = model.try_add_cons_set_part(vec![&x, &y], "c2").unwrap_err();
+        assert_eq!(err.retcode(), Retcode::InvalidData);
+        assert_eq!(err.name(), Some("x"));
+
+        let cons = model.try_add_cons_set_pack(vec![&y], "c3").unwrap();
+        assert_eq!(cons.name(), "c3");
+
+        let solved = model.try_solve().unwrap();
+        assert_eq!(solved.status(), Status::Optimal);
+    }
+
+    #[test]
+    fn try_methods_return_errors() {
+        let err = Model::new()
+            .hide_output()
+            .try_include_default_plugins()
+            .unwrap()
+            .try_create_prob("nul\0name")
+            .unwrap_err();
+        assert_eq!(err.retcode(), Retcode::InvalidData);
+        assert_eq!(err.operation(), "create_prob");
+
+        let mut model = Model::new()
+            .hide_output()
+            .try_set_time_limit(10)
+            .unwrap()
+            .try_set_presolving(ParamSetting::Off)
+            .unwrap()
+            .try_include_default_plugins()
+            .unwrap()
+            .try_create_prob("problem")
+            .unwrap()
+            .try_maximize()
+            .unwrap();
+        assert_eq!(model.real_param("limits/time"), 10.0);
+
+        let err = model
+            .try_add(crate::prelude::var().bin().name("nul\0name"))
+            .unwrap_err();
+        assert_eq!(err.retcode(), Retcode::InvalidData);
+        assert_eq!(err.operation(), "add_var");
+        assert_eq!(err.index(), None);
+
+        // the items before the failing one stay in the model
+        let err = model
+            .try_add(vec![
+                crate::prelude::var().bin().name("y"),
+                crate::prelude::var().bin().name("nul\0name"),
+            ])
+            .unwrap_err();
+        assert_eq!(err.index(), Some(1));
+        assert_eq!(model.n_vars(), 1);
+
+        let x = model
+            .try_add(crate::prelude::var().bin().obj(1.0).name("x"))
+            .unwrap();
+        assert_eq!(x.name(), "x");
+
+        let solved = model.try_solve().unwrap();
+        assert_eq!(solved.obj_val(), 1.0);
+        let model = solved
+            .try_free_transform()
+            .unwrap()
+            .try_reset_params()
+            .unwrap();
+        assert_eq!(model.real_param("limits/time"), 1e20);
+    }
+
+    #[test]
+    fn set_emphasis() {
+        let model = Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .set_emphasis(Emphasis::Feasibility)
+            .read_prob("data/test/simple.lp")
+            .unwrap()
+            .solve();
+
+        assert_eq!(model.status(), Status::Optimal);
+    }
+
+    #[test]
+    fn write_and_read_params() {
+        let path = "data/ignored/test_params.set";
+        Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .set_real_param("limits/gap", 0.05)
+            .unwrap()
+            .set_char_param("lp/initalgorithm", 'p')
+            .unwrap()
+            .write_params(path, true)
+            .unwrap();
+
+        let content = std::fs::read_to_string(path).unwrap();
+        assert!(content.contains("limits/gap = 0.05"));
+        assert!(!content.contains("limits/time"));
+
+        let model = Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .read_params(path)
+            .unwrap();
+        assert_eq!(model.real_param("limits/gap"), 0.05);
+        assert_eq!(model.char_param("lp/initalgorithm"), 'p');
+        // the file was written with output hidden
+        assert_eq!(model.int_param("display/verblevel"), 0);
+        std::fs::remove_file(path).unwrap();
+
+        assert!(Model::new().read_params("does/not/exist.set").is_err());
+    }
+
     #[test]
     fn write_and_read_lp() {
         let model = create_model();
@@ -1884,7 +3986,7 @@ mod tests {
             .set_int_param("display/verblevel", -1)
             .unwrap_err();
 
-        assert_eq!(res, Retcode::ParameterWrongVal);
+        assert_eq!(res.retcode(), Retcode::ParameterWrongVal);
     }
 
     #[test]
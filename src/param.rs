@@ -1,12 +1,12 @@
-use crate::{Model, Retcode};
+use crate::{Error, Model};
 
 pub trait ScipParameter: Sized {
-    fn set<T>(model: Model<T>, name: &str, value: Self) -> Result<Model<T>, Retcode>;
+    fn set<T>(model: Model<T>, name: &str, value: Self) -> Result<Model<T>, Error>;
     fn get<T>(model: &Model<T>, name: &str) -> Self;
 }
 
 impl ScipParameter for f64 {
-    fn set<T>(model: Model<T>, name: &str, value: f64) -> Result<Model<T>, Retcode> {
+    fn set<T>(model: Model<T>, name: &str, value: f64) -> Result<Model<T>, Error> {
         let model = model.set_real_param(name, value)?;
         Ok(model)
     }
@@ -17,7 +17,7 @@ impl ScipParameter for f64 {
 }
 
 impl ScipParameter for i32 {
-    fn set<T>(model: Model<T>, name: &str, value: i32) -> Result<Model<T>, Retcode> {
+    fn set<T>(model: Model<T>, name: &str, value: i32) -> Result<Model<T>, Error> {
         let model = model.set_int_param(name, value)?;
         Ok(model)
     }
@@ -28,7 +28,7 @@ impl ScipParameter for i32 {
 }
 
 impl ScipParameter for bool {
-    fn set<T>(model: Model<T>, name: &str, value: bool) -> Result<Model<T>, Retcode> {
+    fn set<T>(model: Model<T>, name: &str, value: bool) -> Result<Model<T>, Error> {
         let model = model.set_bool_param(name, value)?;
         Ok(model)
     }
@@ -39,7 +39,7 @@ impl ScipParameter for bool {
 }
 
 impl ScipParameter for i64 {
-    fn set<T>(model: Model<T>, name: &str, value: i64) -> Result<Model<T>, Retcode> {
+    fn set<T>(model: Model<T>, name: &str, value: i64) -> Result<Model<T>, Error> {
         let model = model.set_longint_param(name, value)?;
         Ok(model)
     }
@@ -50,7 +50,7 @@ impl ScipParameter for i64 {
 }
 
 impl ScipParameter for String {
-    fn set<T>(model: Model<T>, name: &str, value: String) -> Result<Model<T>, Retcode> {
+    fn set<T>(model: Model<T>, name: &str, value: String) -> Result<Model<T>, Error> {
         let model = model.set_str_param(name, &value)?;
         Ok(model)
     }
@@ -59,3 +59,14 @@ impl ScipParameter for String {
         model.str_param(name)
     }
 }
+
+impl ScipParameter for char {
+    fn set<T>(model: Model<T>, name: &str, value: char) -> Result<Model<T>, Error> {
+        let model = model.set_char_param(name, value)?;
+        Ok(model)
+    }
+
+    fn get<T>(model: &Model<T>, name: &str) -> char {
+        model.char_param(name)
+    }
+}
@@ -0,0 +1,532 @@
+//! The catalogue is a checked-in table of SCIP's most commonly used parameters (see `Model::params` for the full
+//! list of a model at runtime). Parameters that are not listed here can still be set by name through
+//! `Model::set_param`.
+
+use crate::ffi;
+use std::ffi::CStr;
+
+/// A SCIP parameter whose name and value type are known at compile time.
+pub trait Param: Copy {
+    /// The type of the parameter's value.
+    type Value: crate::param::ScipParameter;
+    /// The full name of the parameter, e.g. `limits/time`.
+    const NAME: &'static str;
+}
+
+/// Metadata and current value of a SCIP parameter, as returned by `Model::params`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ParamInfo {
+    /// The full name of the parameter, e.g. `limits/time`.
+    pub name: String,
+    /// The description of the parameter.
+    pub desc: String,
+    /// The type of the parameter with its current value, default value and allowed values.
+    pub kind: ParamKind,
+    /// Whether the parameter is advanced (i.e. not meant to be changed by regular users).
+    pub is_advanced: bool,
+    /// Whether the parameter is fixed, i.e. cannot be changed.
+    pub is_fixed: bool,
+    /// Whether the current value of the parameter equals its default value.
+    pub is_default: bool,
+}
+
+/// The type of a SCIP parameter with its current value, default value and allowed values.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum ParamKind {
+    /// A boolean parameter.
+    Bool {
+        /// The current value.
+        value: bool,
+        /// The default value.
+        default: bool,
+    },
+    /// An integer parameter.
+    Int {
+        /// The current value.
+        value: i32,
+        /// The default value.
+        default: i32,
+        /// The minimal allowed value.
+        min: i32,
+        /// The maximal allowed value.
+        max: i32,
+    },
+    /// A long integer parameter.
+    Longint {
+        /// The current value.
+        value: i64,
+        /// The default value.
+        default: i64,
+        /// The minimal allowed value.
+        min: i64,
+        /// The maximal allowed value.
+        max: i64,
+    },
+    /// A real parameter.
+    Real {
+        /// The current value.
+        value: f64,
+        /// The default value.
+        default: f64,
+        /// The minimal allowed value.
+        min: f64,
+        /// The maximal allowed value.
+        max: f64,
+    },
+    /// A character parameter.
+    Char {
+        /// The current value.
+        value: char,
+        /// The default value.
+        default: char,
+        /// The allowed characters, `None` if any character is allowed.
+        allowed: Option<String>,
+    },
+    /// A string parameter.
+    String {
+        /// The current value.
+        value: String,
+        /// The default value.
+        default: String,
+    },
+}
+
+impl ParamInfo {
+    /// Reads the metadata of a raw SCIP parameter.
+    pub(crate) fn from_raw(param: *mut ffi::SCIP_PARAM) -> Self {
+        let to_string = |ptr: *const std::os::raw::c_char| {
+            if ptr.is_null() {
+                String::new()
+            } else {
+                unsafe { CStr::from_ptr(ptr) }
+                    .to_string_lossy()
+                    .into_owned()
+            }
+        };
+
+        let kind = unsafe {
+            match ffi::SCIPparamGetType(param) {
+                ffi::SCIP_ParamType_SCIP_PARAMTYPE_BOOL => ParamKind::Bool {
+                    value: ffi::SCIPparamGetBool(param) != 0,
+                    default: ffi::SCIPparamGetBoolDefault(param) != 0,
+                },
+                ffi::SCIP_ParamType_SCIP_PARAMTYPE_INT => ParamKind::Int {
+                    value: ffi::SCIPparamGetInt(param),
+                    default: ffi::SCIPparamGetIntDefault(param),
+                    min: ffi::SCIPparamGetIntMin(param),
+                    max: ffi::SCIPparamGetIntMax(param),
+                },
+                ffi::SCIP_ParamType_SCIP_PARAMTYPE_LONGINT => ParamKind::Longint {
+                    value: ffi::SCIPparamGetLongint(param),
+                    default: ffi::SCIPparamGetLongintDefault(param),
+                    min: ffi::SCIPparamGetLongintMin(param),
+                    max: ffi::SCIPparamGetLongintMax(param),
+                },
+                ffi::SCIP_ParamType_SCIP_PARAMTYPE_REAL => ParamKind::Real {
+                    value: ffi::SCIPparamGetReal(param),
+                    default: ffi::SCIPparamGetRealDefault(param),
+                    min: ffi::SCIPparamGetRealMin(param),
+                    max: ffi::SCIPparamGetRealMax(param),
+                },
+                ffi::SCIP_ParamType_SCIP_PARAMTYPE_CHAR => {
+                    let allowed = ffi::SCIPparamGetCharAllowedValues(param);
+                    ParamKind::Char {
+                        value: ffi::SCIPparamGetChar(param) as u8 as char,
+                        default: ffi::SCIPparamGetCharDefault(param) as u8 as char,
+                        allowed: (!allowed.is_null()).then(|| to_string(allowed)),
+                    }
+                }
+                ffi::SCIP_ParamType_SCIP_PARAMTYPE_STRING => ParamKind::String {
+                    value: to_string(ffi::SCIPparamGetString(param)),
+                    default: to_string(ffi::SCIPparamGetStringDefault(param)),
+                },
+                _ => panic!("Unknown SCIP parameter type"),
+            }
+        };
+
+        ParamInfo {
+            name: to_string(unsafe { ffi::SCIPparamGetName(param) }),
+            desc: to_string(unsafe { ffi::SCIPparamGetDesc(param) }),
+            kind,
+            is_advanced: unsafe { ffi::SCIPparamIsAdvanced(param) } != 0,
+            is_fixed: unsafe { ffi::SCIPparamIsFixed(param) } != 0,
+            is_default: unsafe { ffi::SCIPparamIsDefault(param) } != 0,
+        }
+    }
+}
+
+macro_rules! params {
+    ($(
+        $(#[$mod_doc:meta])*
+        mod $module:ident {
+            $(
+                $(#[$doc:meta])*
+                $param:ident: $value:ty = $name:literal;
+            )*
+        }
+    )*) => {
+        $(
+            $(#[$mod_doc])*
+            pub mod $module {
+                $(
+                    $(#[$doc])*
+                    #[doc = concat!("\n\nSCIP parameter `", $name, "`.")]
+                    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+                    pub struct $param;
+
+                    impl super::Param for $param {
+                        type Value = $value;
+                        const NAME: &'static str = $name;
+                    }
+                )*
+            }
+        )*
+    };
+}
+
+params! {
+    /// Parameters for stopping the solving process.
+    mod limits {
+        /// Maximal time in seconds to run.
+        Time: f64 = "limits/time";
+        /// Maximal number of nodes to process (-1: no limit).
+        Nodes: i64 = "limits/nodes";
+        /// Maximal number of total nodes (incl. restarts) to process (-1: no limit).
+        TotalNodes: i64 = "limits/totalnodes";
+        /// Solving stops, if the given number of nodes was processed since the last improvement of the primal
+        /// solution value (-1: no limit).
+        StallNodes: i64 = "limits/stallnodes";
+        /// Maximal memory usage in MB; reported memory usage is lower than real memory usage!
+        Memory: f64 = "limits/memory";
+        /// Solving stops, if the relative gap = |primal - dual|/MIN(|dual|,|primal|) is below the given value.
+        Gap: f64 = "limits/gap";
+        /// Solving stops, if the absolute gap = |primalbound - dualbound| is below the given value.
+        AbsGap: f64 = "limits/absgap";
+        /// Solving stops, if the given number of solutions were found (-1: no limit).
+        Solutions: i32 = "limits/solutions";
+        /// Solving stops, if the given number of solution improvements were found (-1: no limit).
+        BestSol: i32 = "limits/bestsol";
+        /// Maximal number of solutions to store in the solution storage.
+        MaxSol: i32 = "limits/maxsol";
+        /// Maximal number of solutions candidates to store in the solution storage of the original problem.
+        MaxOrigSol: i32 = "limits/maxorigsol";
+        /// Solving stops, if the given number of restarts was triggered (-1: no limit).
+        Restarts: i32 = "limits/restarts";
+        /// If solve exceeds this number of nodes for the first time, an automatic restart is triggered (-1: no
+        /// automatic restart).
+        AutoRestartNodes: i32 = "limits/autorestartnodes";
+    }
+
+    /// Parameters of the LP relaxation.
+    mod lp {
+        /// Number of threads used for solving the LP (0: automatic).
+        Threads: i32 = "lp/threads";
+        /// Frequency for solving LP at the nodes (-1: never; 0: only root LP).
+        SolveFreq: i32 = "lp/solvefreq";
+        /// Iteration limit for each single LP solve (-1: no limit).
+        IterLim: i64 = "lp/iterlim";
+        /// Iteration limit for initial root LP solve (-1: no limit).
+        RootIterLim: i64 = "lp/rootiterlim";
+        /// Maximal depth for solving LP at the nodes (-1: no depth limit).
+        SolveDepth: i32 = "lp/solvedepth";
+        /// LP algorithm for solving initial LP relaxations (automatic 's'implex, 'p'rimal simplex, 'd'ual simplex,
+        /// 'b'arrier, barrier with 'c'rossover).
+        InitAlgorithm: char = "lp/initalgorithm";
+        /// LP algorithm for resolving LP relaxations if a starting basis exists (automatic 's'implex, 'p'rimal
+        /// simplex, 'd'ual simplex, 'b'arrier, barrier with 'c'rossover).
+        ResolveAlgorithm: char = "lp/resolvealgorithm";
+        /// LP pricing strategy ('l'pi default, 'a'uto, 'f'ull pricing, 'p'artial, 's'teepest edge pricing,
+        /// 'q'uickstart steepest edge pricing, 'd'evex pricing).
+        Pricing: char = "lp/pricing";
+        /// LP scaling (0: none, 1: normal, 2: aggressive).
+        Scaling: i32 = "lp/scaling";
+        /// Should presolving of LP solver be used?
+        Presolving: bool = "lp/presolving";
+    }
+
+    /// Parameters of the presolving.
+    mod presolving {
+        /// Maximal number of presolving rounds (-1: unlimited, 0: off).
+        MaxRounds: i32 = "presolving/maxrounds";
+        /// Maximal number of restarts (-1: unlimited).
+        MaxRestarts: i32 = "presolving/maxrestarts";
+        /// Abort presolve, if at most this fraction of the problem was changed in last presolve round.
+        AbortFac: f64 = "presolving/abortfac";
+        /// Should aggregation of variables be forbidden?
+        DoNotAggr: bool = "presolving/donotaggr";
+        /// Should multi-aggregation of variables be forbidden?
+        DoNotMultAggr: bool = "presolving/donotmultaggr";
+    }
+
+    /// Parameters of the separation.
+    mod separating {
+        /// Maximal number of separation rounds per node (-1: unlimited).
+        MaxRounds: i32 = "separating/maxrounds";
+        /// Maximal number of separation rounds in the root node (-1: unlimited).
+        MaxRoundsRoot: i32 = "separating/maxroundsroot";
+        /// Maximal number of cuts separated per separation round (0: disable local separation).
+        MaxCuts: i32 = "separating/maxcuts";
+        /// Maximal number of separated cuts per separation round at the root node (0: disable root node
+        /// separation).
+        MaxCutsRoot: i32 = "separating/maxcutsroot";
+        /// Maximal number of consecutive separation rounds without objective or integrality improvement in local
+        /// nodes (-1: no additional restriction).
+        MaxStallRounds: i32 = "separating/maxstallrounds";
+    }
+
+    /// Parameters of the propagation.
+    mod propagating {
+        /// Maximal number of propagation rounds per node (-1: unlimited).
+        MaxRounds: i32 = "propagating/maxrounds";
+        /// Maximal number of propagation rounds in the root node (-1: unlimited).
+        MaxRoundsRoot: i32 = "propagating/maxroundsroot";
+    }
+
+    /// Parameters of the branching.
+    mod branching {
+        /// Should branching on binary variables be preferred?
+        PreferBinary: bool = "branching/preferbinary";
+        /// Branching score function ('s'um, 'p'roduct, 'q'uotient).
+        ScoreFunc: char = "branching/scorefunc";
+        /// Branching score factor to weigh downward and upward gain prediction in sum score function.
+        ScoreFac: f64 = "branching/scorefac";
+    }
+
+    /// Parameters of the node selection.
+    mod nodeselection {
+        /// Child selection rule ('d'own, 'u'p, 'p'seudo costs, 'i'nference, 'l'p value, 'r'oot LP value
+        /// difference, 'h'ybrid inference/root LP value difference).
+        ChildSel: char = "nodeselection/childsel";
+    }
+
+    /// Parameters of the conflict analysis.
+    mod conflict {
+        /// Should conflict analysis be enabled?
+        Enable: bool = "conflict/enable";
+    }
+
+    /// Numerical tolerances.
+    mod numerics {
+        /// Values larger than this are considered infinity.
+        Infinity: f64 = "numerics/infinity";
+        /// Absolute values smaller than this are considered zero.
+        Epsilon: f64 = "numerics/epsilon";
+        /// Absolute values of sums smaller than this are considered zero.
+        SumEpsilon: f64 = "numerics/sumepsilon";
+        /// Feasibility tolerance for constraints.
+        FeasTol: f64 = "numerics/feastol";
+        /// Feasibility tolerance for reduced costs in LP solution.
+        DualFeasTol: f64 = "numerics/dualfeastol";
+    }
+
+    /// Parameters of the output.
+    mod display {
+        /// Verbosity level of output (0: none, 1: errors, 2: warnings, 3: normal, 4: full, 5: debugging).
+        VerbLevel: i32 = "display/verblevel";
+        /// Frequency for displaying node information lines.
+        Freq: i32 = "display/freq";
+        /// Frequency for displaying header lines (every n'th node information line).
+        HeaderFreq: i32 = "display/headerfreq";
+        /// Maximal number of characters in a node information line.
+        Width: i32 = "display/width";
+        /// Should the LP solver display status messages?
+        LpInfo: bool = "display/lpinfo";
+    }
+
+    /// Parameters of the randomization.
+    mod randomization {
+        /// Global shift of all random seeds in the plugins and the LP random seed.
+        RandomSeedShift: i32 = "randomization/randomseedshift";
+        /// Seed value for permuting the problem after reading/transformation (0: no permutation).
+        PermutationSeed: i32 = "randomization/permutationseed";
+        /// Should order of constraints be permuted (depends on permutationseed)?
+        PermuteConss: bool = "randomization/permuteconss";
+        /// Should order of variables be permuted (depends on permutationseed)?
+        PermuteVars: bool = "randomization/permutevars";
+        /// Random seed for LP solver, e.g. for perturbations in the simplex (0: LP default).
+        LpSeed: i32 = "randomization/lpseed";
+    }
+
+    /// Parameters of the concurrent solver.
+    mod parallel {
+        /// Parallel optimisation mode, 0: opportunistic or 1: deterministic.
+        Mode: i32 = "parallel/mode";
+        /// The minimum number of threads used during parallel solve.
+        MinNThreads: i32 = "parallel/minnthreads";
+        /// The maximum number of threads used during parallel solve.
+        MaxNThreads: i32 = "parallel/maxnthreads";
+    }
+
+    /// Parameters of the concurrent solvers used by `Model::solve_concurrent`.
+    mod concurrent {
+        /// Use different random seeds in each concurrent solver?
+        ChangeSeeds: bool = "concurrent/changeseeds";
+        /// Use different child selection rules in each concurrent solver?
+        ChangeChildSel: bool = "concurrent/changechildsel";
+        /// Should the concurrent solvers communicate global variable bound changes?
+        CommVarBnds: bool = "concurrent/commvarbnds";
+        /// Should the problem be presolved before it is copied to the concurrent solvers?
+        PresolveBefore: bool = "concurrent/presolvebefore";
+        /// Maximum number of solutions that will be shared in a single synchronization.
+        SyncMaxNSols: i32 = "concurrent/sync/maxnsols";
+        /// Initial frequency of synchronization with other threads.
+        SyncFreqInit: f64 = "concurrent/sync/freqinit";
+        /// Maximal frequency of synchronization with other threads.
+        SyncFreqMax: f64 = "concurrent/sync/freqmax";
+        /// Preferred number of concurrent solvers with the settings of the model (relative to the other
+        /// priorities).
+        ScipPrefPrio: f64 = "concurrent/scip/prefprio";
+        /// Preferred number of concurrent solvers with SCIP's default settings.
+        DefaultPrefPrio: f64 = "concurrent/scip-default/prefprio";
+        /// Preferred number of concurrent solvers with settings for constraint programming problems.
+        CpSolverPrefPrio: f64 = "concurrent/scip-cpsolver/prefprio";
+        /// Preferred number of concurrent solvers with settings for easy problems.
+        EasyCipPrefPrio: f64 = "concurrent/scip-easycip/prefprio";
+        /// Preferred number of concurrent solvers with settings emphasizing feasibility.
+        FeasPrefPrio: f64 = "concurrent/scip-feas/prefprio";
+        /// Preferred number of concurrent solvers with settings for problems with hard LP relaxations.
+        HardLpPrefPrio: f64 = "concurrent/scip-hardlp/prefprio";
+        /// Preferred number of concurrent solvers with settings emphasizing optimality.
+        OptiPrefPrio: f64 = "concurrent/scip-opti/prefprio";
+    }
+
+    /// Parameters of the time measurement.
+    mod timing {
+        /// Default clock type (1: CPU user seconds, 2: wall clock time).
+        ClockType: i32 = "timing/clocktype";
+        /// Is timing enabled?
+        Enabled: bool = "timing/enabled";
+        /// Belongs reading time to solving time?
+        Reading: bool = "timing/reading";
+    }
+
+    /// Miscellaneous parameters.
+    mod misc {
+        /// Should the CTRL-C interrupt be caught by SCIP?
+        CatchCtrlC: bool = "misc/catchctrlc";
+        /// Should only solutions be checked which improve the primal bound?
+        ImprovingSols: bool = "misc/improvingsols";
+        /// Should the reason be printed if a given start solution is infeasible?
+        PrintReason: bool = "misc/printreason";
+        /// Should strong dual reductions be allowed in propagation and presolving?
+        AllowStrongDualReds: bool = "misc/allowstrongdualreds";
+        /// Should weak dual reductions be allowed in propagation and presolving?
+        AllowWeakDualReds: bool = "misc/allowweakdualreds";
+        /// Used symmetry handling technique (0: off; 1: polyhedral; 2: orbitopal fixing; ...).
+        UseSymmetry: i32 = "misc/usesymmetry";
+    }
+
+    /// Parameters of the VBC tool output.
+    mod visual {
+        /// Name of the VBC tool output file, or - if no VBC tool output should be created.
+        VbcFilename: String = "visual/vbcfilename";
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::params;
+    use crate::prelude::*;
+
+    #[test]
+    fn typed_params() {
+        let model = Model::new()
+            .hide_output()
+            .set(params::limits::Gap, 0.01)
+            .set(params::limits::Time, 10.)
+            .set(params::limits::Nodes, 100)
+            .set(params::lp::Threads, 2)
+            .set(params::lp::InitAlgorithm, 'd')
+            .set(params::presolving::DoNotMultAggr, true)
+            .set(params::visual::VbcFilename, "data/ignored/typed.vbc");
+
+        assert_eq!(model.get(params::limits::Gap), 0.01);
+        assert_eq!(model.get(params::limits::Time), 10.);
+        assert_eq!(model.get(params::limits::Nodes), 100);
+        assert_eq!(model.get(params::lp::Threads), 2);
+        assert_eq!(model.get(params::lp::InitAlgorithm), 'd');
+        assert!(model.get(params::presolving::DoNotMultAggr));
+        assert_eq!(
+            model.get(params::visual::VbcFilename),
+            "data/ignored/typed.vbc"
+        );
+        assert_eq!(model.real_param(params::limits::Gap::NAME), 0.01);
+    }
+
+    #[test]
+    fn invalid_value() {
+        let res = Model::new()
+            .hide_output()
+            .try_set(params::lp::InitAlgorithm, 'x');
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn list_params() {
+        let model = Model::new().hide_output().include_default_plugins();
+        let params = model.params();
+        assert!(params.len() > 100);
+
+        let gap = params.iter().find(|p| p.name == "limits/gap").unwrap();
+        assert!(!gap.desc.is_empty());
+        assert!(gap.is_default);
+        assert_eq!(
+            gap.kind,
+            ParamKind::Real {
+                value: 0.,
+                default: 0.,
+                min: 0.,
+                max: f64::MAX,
+            }
+        );
+
+        let algo = params
+            .iter()
+            .find(|p| p.name == "lp/initalgorithm")
+            .unwrap();
+        match &algo.kind {
+            ParamKind::Char { allowed, .. } => assert!(allowed.as_ref().unwrap().contains('d')),
+            _ => panic!("lp/initalgorithm should be a char parameter"),
+        }
+    }
+
+    #[test]
+    fn changed_and_reset_params() {
+        let model = Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .set(params::limits::Gap, 0.05)
+            .set(params::lp::Threads, 2);
+
+        let changed: Vec<String> = model.changed_params().into_iter().map(|p| p.name).collect();
+        assert!(changed.contains(&"limits/gap".to_string()));
+        assert!(changed.contains(&"lp/threads".to_string()));
+        // hide_output changes display/verblevel
+        assert!(changed.contains(&"display/verblevel".to_string()));
+
+        let model = model.reset_param("limits/gap").unwrap();
+        assert_eq!(model.get(params::limits::Gap), 0.);
+        assert!(model
+            .changed_params()
+            .iter()
+            .all(|p| p.name != "limits/gap"));
+
+        let model = model.reset_params();
+        assert!(model.changed_params().is_empty());
+        assert!(model.reset_param("does/not/exist").is_err());
+    }
+
+    #[test]
+    fn char_param() {
+        let model = Model::new()
+            .hide_output()
+            .set_param("branching/scorefunc", 'p');
+        assert_eq!(model.param::<char>("branching/scorefunc"), 'p');
+
+        let err = model.try_set_param("branching/scorefunc", 'π').unwrap_err();
+        assert_eq!(err.retcode(), Retcode::ParameterWrongVal);
+    }
+}
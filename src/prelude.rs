@@ -1,5 +1,6 @@
 pub use crate::builder::branchrule::branchrule;
 pub use crate::builder::cons::cons;
+pub use crate::builder::disp::display_column;
 pub use crate::builder::eventhdlr::eventhdlr;
 pub use crate::builder::heur::heur;
 pub use crate::builder::pricer::pricer;
@@ -0,0 +1,241 @@
+use crate::{Error, LpSolveResult, Model, Retcode, Solving, Variable};
+
+/// The result of propagating a probing node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PropagationResult {
+    /// Whether the probing node was detected to be infeasible.
+    pub cutoff: bool,
+    /// The number of domain reductions found.
+    pub n_dom_reds: usize,
+}
+
+/// Probing mode, obtained through [`Model::start_probing`](crate::Model::start_probing).
+///
+/// In probing mode, temporary nodes can be created below the current node, in which variables can be fixed and
+/// their bounds changed, followed by propagation and solving the LP. This allows, e.g., writing fix-and-propagate
+/// heuristics. Probing ends, and all changes are undone, by [`end`](ProbingGuard::end) or when the guard is dropped.
+#[derive(Debug)]
+pub struct ProbingGuard<'a> {
+    model: &'a mut Model<Solving>,
+}
+
+impl<'a> ProbingGuard<'a> {
+    pub(crate) fn new(model: &'a mut Model<Solving>) -> Self {
+        ProbingGuard { model }
+    }
+
+    /// Returns the model, e.g. to read the local bounds of variables through
+    /// [`Variable::lb_local`](crate::Variable::lb_local).
+    pub fn model(&self) -> &Model<Solving> {
+        self.model
+    }
+
+    /// Creates a new probing node below the current probing node, changes are then applied to the new node.
+    pub fn new_probing_node(&mut self) -> Result<(), Error> {
+        self.model
+            .scip
+            .try_call("new_probing_node", None, |scip| scip.new_probing_node())
+    }
+
+    /// Returns the current probing depth, i.e., the number of probing nodes created (and not backtracked).
+    pub fn depth(&self) -> usize {
+        self.model.scip.probing_depth()
+    }
+
+    /// Fixes a variable at the current probing node.
+    pub fn fix_var_probing(&mut self, var: &Variable, val: f64) -> Result<(), Error> {
+        self.model
+            .scip
+            .try_call("fix_var_probing", Some(&var.name()), |scip| {
+                scip.fix_var_probing(var, val)
+            })
+    }
+
+    /// Changes the lower bound of a variable at the current probing node.
+    pub fn chg_var_lb_probing(&mut self, var: &Variable, lb: f64) -> Result<(), Error> {
+        self.model
+            .scip
+            .try_call("chg_var_lb_probing", Some(&var.name()), |scip| {
+                scip.chg_var_lb_probing(var, lb)
+            })
+    }
+
+    /// Changes the upper bound of a variable at the current probing node.
+    pub fn chg_var_ub_probing(&mut self, var: &Variable, ub: f64) -> Result<(), Error> {
+        self.model
+            .scip
+            .try_call("chg_var_ub_probing", Some(&var.name()), |scip| {
+                scip.chg_var_ub_probing(var, ub)
+            })
+    }
+
+    /// Applies domain propagation at the current probing node.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_rounds` - The maximal number of propagation rounds, `None` for no limit and `Some(0)` for the value of
+    ///   the `propagating/maxrounds` parameter.
+    pub fn propagate_probing(
+        &mut self,
+        max_rounds: Option<usize>,
+    ) -> Result<PropagationResult, Error> {
+        self.model.scip.try_call("propagate_probing", None, |scip| {
+            scip.propagate_probing(max_rounds)
+        })
+    }
+
+    /// Solves the LP at the current probing node, without separation or pricing.
+    ///
+    /// # Arguments
+    ///
+    /// * `iteration_limit` - The maximal number of LP iterations, or `None` for no limit.
+    pub fn solve_probing_lp(
+        &mut self,
+        iteration_limit: Option<usize>,
+    ) -> Result<LpSolveResult, Error> {
+        self.model.scip.try_call("solve_probing_lp", None, |scip| {
+            scip.solve_probing_lp(iteration_limit)
+        })
+    }
+
+    /// Undoes all changes of the probing nodes below the given probing depth, `0` returns to the node where probing
+    /// started.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `InvalidData` error if `depth` is larger than the current probing [`depth`](ProbingGuard::depth).
+    pub fn backtrack_probing(&mut self, depth: usize) -> Result<(), Error> {
+        let current_depth = self.depth();
+        if depth > current_depth {
+            return Err(Error::new(
+                Retcode::InvalidData,
+                "backtrack_probing",
+                None,
+                self.model.stage(),
+            )
+            .with_message(&format!(
+                "cannot backtrack to probing depth {depth}, the current probing depth is {current_depth}"
+            )));
+        }
+        self.model.scip.try_call("backtrack_probing", None, |scip| {
+            scip.backtrack_probing(depth)
+        })
+    }
+
+    /// Returns the objective value of the LP solved last.
+    pub fn lp_obj_val(&self) -> f64 {
+        self.model.scip.lp_obj_val()
+    }
+
+    /// Ends probing and undoes all changes, returning an [`Error`] if SCIP fails to do so.
+    pub fn end(self) -> Result<(), Error> {
+        let result = self.end_probing();
+        // probing is over, dropping the guard would end it a second time
+        std::mem::forget(self);
+        result
+    }
+
+    fn end_probing(&self) -> Result<(), Error> {
+        self.model
+            .scip
+            .try_call("end_probing", None, |scip| scip.end_probing())
+    }
+}
+
+impl Drop for ProbingGuard<'_> {
+    fn drop(&mut self) {
+        // panicking here could abort the process while unwinding, so failures are only reported as a warning
+        if let Err(err) = self.end_probing() {
+            self.model.scip.warning_message(&err.to_string());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use crate::{HeurResult, HeurTiming, Heuristic, ParamSetting};
+    use std::sync::{Arc, Mutex};
+
+    struct FixAndPropagateHeur {
+        n_calls: Arc<Mutex<usize>>,
+    }
+
+    impl Heuristic for FixAndPropagateHeur {
+        fn execute(
+            &mut self,
+            mut model: Model<Solving>,
+            _timing: HeurTiming,
+            _node_inf: bool,
+        ) -> HeurResult {
+            let vars = model.vars();
+            let (x, y, z) = (&vars[0], &vars[1], &vars[2]);
+            assert!(!model.in_probing());
+
+            let mut probing = model.start_probing().unwrap();
+            assert!(probing.model().in_probing());
+
+            probing.new_probing_node().unwrap();
+            probing.fix_var_probing(x, 1.0).unwrap();
+            let res = probing.propagate_probing(None).unwrap();
+            assert!(!res.cutoff);
+            assert!(res.n_dom_reds >= 1);
+            assert_eq!(y.ub_local(), 0.0);
+
+            probing.new_probing_node().unwrap();
+            probing.fix_var_probing(z, 1.0).unwrap();
+            assert_eq!(probing.depth(), 2);
+            let res = probing.solve_probing_lp(None).unwrap();
+            assert!(!res.lp_error && !res.cutoff);
+
+            let err = probing.backtrack_probing(3).unwrap_err();
+            assert_eq!(err.retcode(), Retcode::InvalidData);
+            assert_eq!(probing.depth(), 2);
+            probing.backtrack_probing(0).unwrap();
+            assert_eq!(probing.depth(), 0);
+            assert_eq!(y.ub_local(), 1.0);
+
+            probing.new_probing_node().unwrap();
+            probing.fix_var_probing(y, 1.0).unwrap();
+            let res = probing.propagate_probing(None).unwrap();
+            assert!(!res.cutoff);
+            assert_eq!(x.ub_local(), 0.0);
+            assert_eq!(z.ub_local(), 0.0);
+            drop(probing);
+
+            assert!(!model.in_probing());
+            assert_eq!(x.ub_local(), 1.0);
+            *self.n_calls.lock().unwrap() += 1;
+            HeurResult::DidNotRun
+        }
+    }
+
+    #[test]
+    fn probing() {
+        let mut model = Model::new()
+            .hide_output()
+            .set_presolving(ParamSetting::Off)
+            .include_default_plugins()
+            .create_prob("probing")
+            .maximize();
+        let x = model.add(var().bin().obj(1.0).name("x"));
+        let y = model.add(var().bin().obj(1.0).name("y"));
+        let z = model.add(var().bin().obj(1.0).name("z"));
+        model.add(cons().coef(&x, 1.0).coef(&y, 1.0).le(1.0));
+        model.add(cons().coef(&y, 1.0).coef(&z, 1.0).le(1.0));
+
+        let n_calls = Arc::new(Mutex::new(0));
+        model.add(
+            heur(FixAndPropagateHeur {
+                n_calls: n_calls.clone(),
+            })
+            .name("fix_and_propagate_heur")
+            .timing(HeurTiming::AFTER_LP_NODE),
+        );
+        let solved = model.solve();
+        assert_eq!(solved.status(), Status::Optimal);
+        assert_eq!(solved.obj_val(), 2.0);
+        assert!(*n_calls.lock().unwrap() > 0);
+    }
+}
@@ -215,6 +215,17 @@ impl From<ffi::SCIP_BASESTAT> for BasisStatus {
     }
 }
 
+impl From<BasisStatus> for ffi::SCIP_BASESTAT {
+    fn from(status: BasisStatus) -> Self {
+        match status {
+            BasisStatus::Lower => ffi::SCIP_BaseStat_SCIP_BASESTAT_LOWER,
+            BasisStatus::Basic => ffi::SCIP_BaseStat_SCIP_BASESTAT_BASIC,
+            BasisStatus::Upper => ffi::SCIP_BaseStat_SCIP_BASESTAT_UPPER,
+            BasisStatus::Zero => ffi::SCIP_BaseStat_SCIP_BASESTAT_ZERO,
+        }
+    }
+}
+
 /// The origin type of row.
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum RowOrigin {
@@ -1,18 +1,46 @@
+use crate::benders::DEFAULT_BENDERS;
 use crate::branchrule::{BranchRule, BranchingCandidate};
+use crate::display::fit_to_width;
+use crate::message::{is_stdout, MessageHandlerData, SharedMessageHandler};
+use crate::params::ParamInfo;
 use crate::pricer::{Pricer, PricerResultState};
 use crate::{
-    ffi, scip_call_panic, BranchingResult, Constraint, Event, Eventhdlr, HeurResult, Model,
-    ObjSense, ParamSetting, Retcode, Row, SCIPBranchRule, SCIPEventhdlr, SCIPPricer, SCIPSeparator,
-    Separator, Solution, Solving, Status, VarType, Variable,
+    ffi, scip_call_panic, BranchingResult, Constraint, Emphasis, Event, Eventhdlr, HeurResult,
+    Model, ObjSense, ParamSetting, Retcode, Row, SCIPBenders, SCIPBranchRule, SCIPEventhdlr,
+    SCIPPricer, SCIPSeparator, Separator, Solution, SolutionValues, Solving, Stage, Status,
+    VarType, Variable,
+};
+use crate::{
+    scip_call, BasisIndex, BendersCut, BoundType, DisplayColumn, DisplayStatus, HeurTiming,
+    Heuristic, InterruptHandle, LpSolStat, LpSolveResult, MessageHandler, MessageLevel,
+    PropagationResult,
 };
-use crate::{scip_call, HeurTiming, Heuristic};
 use core::panic;
 use scip_sys::{SCIP_Cons, SCIP_Var, Scip, SCIP_NODE, SCIP_SOL};
-use std::collections::BTreeMap;
+use std::cell::{Cell, OnceCell, RefCell};
+use std::collections::{BTreeMap, HashMap};
 use std::ffi::{c_int, CStr, CString};
 use std::mem::MaybeUninit;
 use std::rc::Rc;
 
+/// Converts a name or path to a C string, strings with interior nul bytes are rejected as invalid data.
+fn c_string(value: &str) -> Result<CString, Retcode> {
+    CString::new(value).map_err(|_| Retcode::InvalidData)
+}
+
+/// A copy of a SCIP instance, see `ScipPtr::copy_orig`.
+#[derive(Debug)]
+pub(crate) struct ScipCopy {
+    /// The new SCIP instance
+    pub(crate) scip: ScipPtr,
+    /// Maps the variables of the source instance to their copies
+    pub(crate) vars: HashMap<*mut SCIP_Var, *mut SCIP_Var>,
+    /// Maps the constraints of the source instance to their copies
+    pub(crate) conss: HashMap<*mut SCIP_Cons, *mut SCIP_Cons>,
+    /// Whether all problem data could be copied
+    pub(crate) valid: bool,
+}
+
 #[non_exhaustive]
 #[derive(Debug)]
 pub struct ScipPtr {
@@ -22,6 +50,14 @@ pub struct ScipPtr {
     pub(crate) weak: bool,
     /// Variables added during solving (to be released after solving)
     vars_added_in_solving: Vec<*mut ffi::SCIP_VAR>,
+    /// Handle used to interrupt the solving process, created on first request
+    pub(crate) interrupt: OnceCell<InterruptHandle>,
+    /// If true, user-provided plugins or a message handler were included, which are not required to be `Send`
+    pub(crate) thread_bound: Cell<bool>,
+    /// Subproblems of the Benders' decomposition, freed after this SCIP instance which uses them
+    benders_subproblems: RefCell<Vec<Rc<ScipPtr>>>,
+    /// The message handler set by the user, which receives the error messages of the calls on this instance
+    pub(crate) message_handler: RefCell<Option<SharedMessageHandler>>,
 }
 
 impl ScipPtr {
@@ -33,6 +69,10 @@ impl ScipPtr {
             raw: scip_ptr,
             weak: false,
             vars_added_in_solving: Vec::new(),
+            interrupt: OnceCell::new(),
+            thread_bound: Cell::new(false),
+            benders_subproblems: RefCell::new(Vec::new()),
+            message_handler: RefCell::new(None),
         }
     }
 
@@ -41,18 +81,22 @@ impl ScipPtr {
             raw,
             weak,
             vars_added_in_solving: Vec::new(),
+            interrupt: OnceCell::new(),
+            thread_bound: Cell::new(false),
+            benders_subproblems: RefCell::new(Vec::new()),
+            message_handler: RefCell::new(None),
         }
     }
 
     pub(crate) fn set_str_param(&self, param: &str, value: &str) -> Result<(), Retcode> {
-        let param = CString::new(param).unwrap();
-        let value = CString::new(value).unwrap();
+        let param = c_string(param)?;
+        let value = c_string(value)?;
         scip_call! { ffi::SCIPsetStringParam(self.raw, param.as_ptr(), value.as_ptr()) };
         Ok(())
     }
 
     pub(crate) fn str_param(&self, param: &str) -> Result<&str, Retcode> {
-        let param = CString::new(param).unwrap();
+        let param = c_string(param)?;
         let mut value_ptr = MaybeUninit::uninit();
         scip_call! { ffi::SCIPgetStringParam(self.raw, param.as_ptr(), value_ptr.as_mut_ptr()) };
         let value_ptr = unsafe { value_ptr.assume_init() };
@@ -61,13 +105,13 @@ impl ScipPtr {
     }
 
     pub(crate) fn set_bool_param(&self, param: &str, value: bool) -> Result<(), Retcode> {
-        let param = CString::new(param).unwrap();
+        let param = c_string(param)?;
         scip_call! { ffi::SCIPsetBoolParam(self.raw, param.as_ptr(), if value { 1u32 } else { 0u32 }) };
         Ok(())
     }
 
     pub(crate) fn bool_param(&self, param: &str) -> Result<bool, Retcode> {
-        let param = CString::new(param).unwrap();
+        let param = c_string(param)?;
         let mut value = MaybeUninit::uninit();
         scip_call! { ffi::SCIPgetBoolParam(self.raw, param.as_ptr(), value.as_mut_ptr()) };
         let value = unsafe { value.assume_init() };
@@ -75,13 +119,13 @@ impl ScipPtr {
     }
 
     pub(crate) fn set_int_param(&self, param: &str, value: i32) -> Result<(), Retcode> {
-        let param = CString::new(param).unwrap();
+        let param = c_string(param)?;
         scip_call! { ffi::SCIPsetIntParam(self.raw, param.as_ptr(), value) };
         Ok(())
     }
 
     pub(crate) fn int_param(&self, param: &str) -> Result<i32, Retcode> {
-        let param = CString::new(param).unwrap();
+        let param = c_string(param)?;
         let mut value = MaybeUninit::uninit();
         scip_call! { ffi::SCIPgetIntParam(self.raw, param.as_ptr(), value.as_mut_ptr()) };
         let value = unsafe { value.assume_init() };
@@ -89,33 +133,70 @@ impl ScipPtr {
     }
 
     pub(crate) fn set_longint_param(&self, param: &str, value: i64) -> Result<(), Retcode> {
-        let param = CString::new(param).unwrap();
+        let param = c_string(param)?;
         scip_call! { ffi::SCIPsetLongintParam(self.raw, param.as_ptr(), value) };
         Ok(())
     }
 
     pub(crate) fn longint_param(&self, param: &str) -> Result<i64, Retcode> {
-        let param = CString::new(param).unwrap();
+        let param = c_string(param)?;
         let mut value = MaybeUninit::uninit();
         scip_call! { ffi::SCIPgetLongintParam(self.raw, param.as_ptr(), value.as_mut_ptr()) };
         let value = unsafe { value.assume_init() };
         Ok(value)
     }
 
+    pub(crate) fn set_char_param(&self, param: &str, value: char) -> Result<(), Retcode> {
+        // SCIP stores a single C char, other characters would be truncated to an arbitrary value
+        if !value.is_ascii() {
+            return Err(Retcode::ParameterWrongVal);
+        }
+        let param = c_string(param)?;
+        scip_call! { ffi::SCIPsetCharParam(self.raw, param.as_ptr(), value as std::os::raw::c_char) };
+        Ok(())
+    }
+
+    pub(crate) fn char_param(&self, param: &str) -> Result<char, Retcode> {
+        let param = c_string(param)?;
+        let mut value = MaybeUninit::uninit();
+        scip_call! { ffi::SCIPgetCharParam(self.raw, param.as_ptr(), value.as_mut_ptr()) };
+        let value = unsafe { value.assume_init() };
+        Ok(value as u8 as char)
+    }
+
     pub(crate) fn set_real_param(&self, param: &str, value: f64) -> Result<(), Retcode> {
-        let param = CString::new(param).unwrap();
+        let param = c_string(param)?;
         scip_call! { ffi::SCIPsetRealParam(self.raw, param.as_ptr(), value) };
         Ok(())
     }
 
     pub(crate) fn real_param(&self, param: &str) -> Result<f64, Retcode> {
-        let param = CString::new(param).unwrap();
+        let param = c_string(param)?;
         let mut value = MaybeUninit::uninit();
         scip_call! { ffi::SCIPgetRealParam(self.raw, param.as_ptr(), value.as_mut_ptr()) };
         let value = unsafe { value.assume_init() };
         Ok(value)
     }
 
+    pub(crate) fn params(&self) -> Vec<ParamInfo> {
+        let n_params = unsafe { ffi::SCIPgetNParams(self.raw) };
+        let params = unsafe { ffi::SCIPgetParams(self.raw) };
+        (0..n_params as usize)
+            .map(|i| ParamInfo::from_raw(unsafe { *params.add(i) }))
+            .collect()
+    }
+
+    pub(crate) fn reset_param(&self, param: &str) -> Result<(), Retcode> {
+        let param = c_string(param)?;
+        scip_call! { ffi::SCIPresetParam(self.raw, param.as_ptr()) };
+        Ok(())
+    }
+
+    pub(crate) fn reset_params(&self) -> Result<(), Retcode> {
+        scip_call! { ffi::SCIPresetParams(self.raw) };
+        Ok(())
+    }
+
     pub(crate) fn set_presolving(&self, presolving: ParamSetting) -> Result<(), Retcode> {
         scip_call! { ffi::SCIPsetPresolving(self.raw, presolving.into(), true.into()) };
         Ok(())
@@ -131,14 +212,31 @@ impl ScipPtr {
         Ok(())
     }
 
+    pub(crate) fn set_emphasis(&self, emphasis: Emphasis) -> Result<(), Retcode> {
+        scip_call! { ffi::SCIPsetEmphasis(self.raw, emphasis.into(), true.into()) };
+        Ok(())
+    }
+
+    pub(crate) fn read_params(&self, path: &str) -> Result<(), Retcode> {
+        let c_path = c_string(path)?;
+        scip_call! { ffi::SCIPreadParams(self.raw, c_path.as_ptr()) };
+        Ok(())
+    }
+
+    pub(crate) fn write_params(&self, path: &str, only_changed: bool) -> Result<(), Retcode> {
+        let c_path = c_string(path)?;
+        scip_call! { ffi::SCIPwriteParams(self.raw, c_path.as_ptr(), true.into(), only_changed.into()) };
+        Ok(())
+    }
+
     pub(crate) fn create_prob(&self, name: &str) -> Result<(), Retcode> {
-        let name = CString::new(name).unwrap();
+        let name = c_string(name)?;
         scip_call!(ffi::SCIPcreateProbBasic(self.raw, name.as_ptr()));
         Ok(())
     }
 
     pub(crate) fn read_prob(&self, filename: &str) -> Result<(), Retcode> {
-        let filename = CString::new(filename).unwrap();
+        let filename = c_string(filename)?;
         scip_call!(ffi::SCIPreadProb(
             self.raw,
             filename.as_ptr(),
@@ -173,13 +271,23 @@ impl ScipPtr {
         status.into()
     }
 
+    pub(crate) fn stage(&self) -> Stage {
+        unsafe { ffi::SCIPgetStage(self.raw) }.into()
+    }
+
     pub(crate) fn print_version(&self) {
         unsafe { ffi::SCIPprintVersion(self.raw, std::ptr::null_mut()) };
     }
 
+    /// Prints a warning through the message handler of the instance.
+    pub(crate) fn warning_message(&self, msg: &str) {
+        let msg = CString::new(msg.replace('\0', "")).unwrap();
+        unsafe { ffi::SCIPwarningMessage(self.raw, c"%s\n".as_ptr(), msg.as_ptr()) };
+    }
+
     pub(crate) fn write(&self, path: &str, ext: &str) -> Result<(), Retcode> {
-        let c_path = CString::new(path).unwrap();
-        let c_ext = CString::new(ext).unwrap();
+        let c_path = c_string(path)?;
+        let c_ext = c_string(ext)?;
         scip_call! { ffi::SCIPwriteOrigProblem(
             self.raw,
             c_path.as_ptr(),
@@ -189,6 +297,103 @@ impl ScipPtr {
         Ok(())
     }
 
+    pub(crate) fn set_message_handler(
+        &self,
+        handler: Box<dyn MessageHandler>,
+    ) -> Result<(), Retcode> {
+        let handler = std::rc::Rc::new(RefCell::new(handler));
+        *self.message_handler.borrow_mut() = Some(handler.clone());
+        let mut messagehdlr = Self::create_message_handler(handler)?;
+        scip_call! { ffi::SCIPsetMessagehdlr(self.raw, messagehdlr) };
+        scip_call! { ffi::SCIPmessagehdlrRelease(&mut messagehdlr) };
+        Ok(())
+    }
+
+    /// Creates a SCIP message handler forwarding the output to the given handler, the caller owns the returned
+    /// handler and has to release it.
+    fn create_message_handler(
+        handler: SharedMessageHandler,
+    ) -> Result<*mut ffi::SCIP_MESSAGEHDLR, Retcode> {
+        fn push_message(
+            messagehdlr: *mut ffi::SCIP_MESSAGEHDLR,
+            file: *mut ffi::FILE,
+            msg: *const std::os::raw::c_char,
+            level: MessageLevel,
+        ) {
+            if msg.is_null() {
+                return;
+            }
+            // messages written to an explicit file (e.g. a settings file) are not routed to the handler
+            if !is_stdout(file) {
+                unsafe { ffi::fputs(msg, file) };
+                return;
+            }
+            let data =
+                unsafe { ffi::SCIPmessagehdlrGetData(messagehdlr) } as *mut MessageHandlerData;
+            let data = unsafe { &mut *data };
+            let msg = unsafe { CStr::from_ptr(msg) }.to_string_lossy();
+            data.push(level, &msg);
+        }
+
+        unsafe extern "C" fn message_info(
+            messagehdlr: *mut ffi::SCIP_MESSAGEHDLR,
+            file: *mut ffi::FILE,
+            msg: *const std::os::raw::c_char,
+        ) {
+            push_message(messagehdlr, file, msg, MessageLevel::Info);
+        }
+
+        unsafe extern "C" fn message_warning(
+            messagehdlr: *mut ffi::SCIP_MESSAGEHDLR,
+            file: *mut ffi::FILE,
+            msg: *const std::os::raw::c_char,
+        ) {
+            push_message(messagehdlr, file, msg, MessageLevel::Warning);
+        }
+
+        unsafe extern "C" fn message_dialog(
+            messagehdlr: *mut ffi::SCIP_MESSAGEHDLR,
+            file: *mut ffi::FILE,
+            msg: *const std::os::raw::c_char,
+        ) {
+            push_message(messagehdlr, file, msg, MessageLevel::Dialog);
+        }
+
+        unsafe extern "C" fn message_free(
+            messagehdlr: *mut ffi::SCIP_MESSAGEHDLR,
+        ) -> ffi::SCIP_Retcode {
+            let data =
+                unsafe { ffi::SCIPmessagehdlrGetData(messagehdlr) } as *mut MessageHandlerData;
+            if !data.is_null() {
+                let mut data = unsafe { Box::from_raw(data) };
+                data.flush();
+            }
+            ffi::SCIP_Retcode_SCIP_OKAY
+        }
+
+        let data = Box::into_raw(Box::new(MessageHandlerData::new(handler)));
+
+        let mut messagehdlr = MaybeUninit::uninit();
+        let retcode = unsafe {
+            ffi::SCIPmessagehdlrCreate(
+                messagehdlr.as_mut_ptr(),
+                0,
+                std::ptr::null(),
+                0,
+                Some(message_warning),
+                Some(message_dialog),
+                Some(message_info),
+                Some(message_free),
+                data as *mut ffi::SCIP_MESSAGEHDLRDATA,
+            )
+        };
+        if retcode != ffi::SCIP_Retcode_SCIP_OKAY {
+            drop(unsafe { Box::from_raw(data) });
+            return Err(Retcode::from(retcode));
+        }
+        Ok(unsafe { messagehdlr.assume_init() })
+    }
+
     pub(crate) fn include_default_plugins(&self) -> Result<(), Retcode> {
         scip_call!(ffi::SCIPincludeDefaultPlugins(self.raw));
         Ok(())
@@ -230,11 +435,335 @@ impl ScipPtr {
         conss
     }
 
+    pub(crate) fn orig_vars(&self) -> Vec<*mut SCIP_Var> {
+        let n_vars = unsafe { ffi::SCIPgetNOrigVars(self.raw) } as usize;
+        let vars = unsafe { ffi::SCIPgetOrigVars(self.raw) };
+        (0..n_vars).map(|i| unsafe { *vars.add(i) }).collect()
+    }
+
+    pub(crate) fn orig_conss(&self) -> Vec<*mut SCIP_Cons> {
+        let n_conss = unsafe { ffi::SCIPgetNOrigConss(self.raw) } as usize;
+        let conss = unsafe { ffi::SCIPgetOrigConss(self.raw) };
+        (0..n_conss).map(|i| unsafe { *conss.add(i) }).collect()
+    }
+
+    /// Copies the problem, together with the plugins and parameters, into a new SCIP instance. The variables and
+    /// constraints of the copy are captured like those of a problem that was read from a file.
+    ///
+    /// # Arguments
+    ///
+    /// * `original` - Whether to copy the original problem (`SCIPcopyOrig`) instead of the current, possibly presolved,
+    ///   problem with its global bounds (`SCIPcopy`).
+    /// * `pass_messagehdlr` - Whether the copy shares the message handler, only for copies used on the same thread.
+    pub(crate) fn copy(&self, original: bool, pass_messagehdlr: bool) -> Result<ScipCopy, Retcode> {
+        let mut target = ScipPtr::new();
+        // the copied variables and constraints are not captured yet, so they must not be released on failure
+        target.weak = true;
+        match self.copy_into(&target, original, pass_messagehdlr) {
+            Ok((vars, conss, valid)) => {
+                target.vars(true);
+                target.conss(true);
+                target.weak = false;
+                target
+                    .thread_bound
+                    .set(pass_messagehdlr && self.thread_bound.get());
+                if pass_messagehdlr {
+                    *target.message_handler.borrow_mut() = self.message_handler.borrow().clone();
+                }
+                Ok(ScipCopy {
+                    scip: target,
+                    vars,
+                    conss,
+                    valid,
+                })
+            }
+            Err(retcode) => {
+                unsafe { ffi::SCIPfree(&mut target.raw) };
+                Err(retcode)
+            }
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn copy_into(
+        &self,
+        target: &ScipPtr,
+        original: bool,
+        pass_messagehdlr: bool,
+    ) -> Result<
+        (
+            HashMap<*mut SCIP_Var, *mut SCIP_Var>,
+            HashMap<*mut SCIP_Cons, *mut SCIP_Cons>,
+            bool,
+        ),
+        Retcode,
+    > {
+        let (source_vars, source_conss) = if original {
+            (self.orig_vars(), self.orig_conss())
+        } else {
+            (self.vars(false).into_values().collect(), self.conss(false))
+        };
+        let blkmem = unsafe { ffi::SCIPblkmem(target.raw) };
+        let mut varmap = std::ptr::null_mut();
+        let mut consmap = std::ptr::null_mut();
+        scip_call!(ffi::SCIPhashmapCreate(
+            &mut varmap,
+            blkmem,
+            source_vars.len().max(1) as c_int
+        ));
+        let retcode = Retcode::from(unsafe {
+            ffi::SCIPhashmapCreate(&mut consmap, blkmem, source_conss.len().max(1) as c_int)
+        });
+        if retcode != Retcode::Okay {
+            unsafe { ffi::SCIPhashmapFree(&mut varmap) };
+            return Err(retcode);
+        }
+
+        let mut valid = 0;
+        let retcode = Retcode::from(unsafe {
+            if original {
+                ffi::SCIPcopyOrig(
+                    self.raw,
+                    target.raw,
+                    varmap,
+                    consmap,
+                    c"".as_ptr(),
+                    0,
+                    0,
+                    pass_messagehdlr.into(),
+                    &mut valid,
+                )
+            } else {
+                ffi::SCIPcopy(
+                    self.raw,
+                    target.raw,
+                    varmap,
+                    consmap,
+                    c"".as_ptr(),
+                    1,
+                    0,
+                    0,
+                    pass_messagehdlr.into(),
+                    &mut valid,
+                )
+            }
+        });
+        let vars = source_vars
+            .into_iter()
+            .map(|var| {
+                let image = unsafe { ffi::SCIPhashmapGetImage(varmap, var.cast()) };
+                (var, image.cast::<SCIP_Var>())
+            })
+            .filter(|(_, image)| !image.is_null())
+            .collect();
+        let conss = source_conss
+            .into_iter()
+            .map(|cons| {
+                let image = unsafe { ffi::SCIPhashmapGetImage(consmap, cons.cast()) };
+                (cons, image.cast::<SCIP_Cons>())
+            })
+            .filter(|(_, image)| !image.is_null())
+            .collect();
+        unsafe {
+            ffi::SCIPhashmapFree(&mut consmap);
+            ffi::SCIPhashmapFree(&mut varmap);
+        }
+
+        if retcode != Retcode::Okay {
+            return Err(retcode);
+        }
+        Ok((vars, conss, valid != 0))
+    }
+
+    /// Solves a copy of the original problem without the given constraints and variable bounds, and returns whether
+    /// it was proven to be infeasible.
+    pub(crate) fn is_infeasible_without(
+        &self,
+        conss: &[*mut SCIP_Cons],
+        bounds: &[(*mut SCIP_Var, BoundType)],
+    ) -> Result<bool, Retcode> {
+        let copy = self.copy(true, false)?;
+        if !copy.valid {
+            return Err(Retcode::InvalidData);
+        }
+        let target = copy.scip.raw;
+        unsafe { ffi::SCIPsetMessagehdlrQuiet(target, 1) };
+        for cons in conss {
+            let mut cons = copy.conss[cons];
+            scip_call!(ffi::SCIPdelCons(target, cons));
+            scip_call!(ffi::SCIPreleaseCons(target, &mut cons));
+        }
+        let infinity = unsafe { ffi::SCIPinfinity(target) };
+        for (var, bound) in bounds {
+            let var = copy.vars[var];
+            match bound {
+                BoundType::Lower => {
+                    scip_call!(ffi::SCIPchgVarLb(target, var, -infinity));
+                }
+                BoundType::Upper => {
+                    scip_call!(ffi::SCIPchgVarUb(target, var, infinity));
+                }
+            }
+        }
+        copy.scip.solve()?;
+        Ok(copy.scip.status() == Status::Infeasible)
+    }
+
     pub(crate) fn solve(&self) -> Result<(), Retcode> {
         scip_call!(ffi::SCIPsolve(self.raw));
         Ok(())
     }
 
+    pub(crate) fn count(&self, limit: Option<usize>, collect: bool) -> Result<(), Retcode> {
+        self.set_emphasis(Emphasis::Counter)?;
+        let limit = limit.map_or(-1, |limit| i64::try_from(limit).unwrap_or(i64::MAX));
+        self.set_longint_param("constraints/countsols/sollimit", limit)?;
+        if collect {
+            self.set_bool_param("constraints/countsols/collect", true)?;
+            // the collected solutions refer to the transformed variables, which presolving must not remove
+            self.set_presolving(ParamSetting::Off)?;
+        }
+        scip_call!(ffi::SCIPcount(self.raw));
+        Ok(())
+    }
+
+    pub(crate) fn n_counted_sols(&self) -> (u64, bool) {
+        let mut valid = 0;
+        let n_sols = unsafe { ffi::SCIPgetNCountedSols(self.raw, &mut valid) };
+        (n_sols as u64, valid != 0)
+    }
+
+    pub(crate) fn counted_sols(&self, limit: Option<usize>) -> Vec<SolutionValues> {
+        let mut vars = std::ptr::null_mut();
+        let mut n_vars = 0;
+        let mut sparse_sols = std::ptr::null_mut();
+        let mut n_sparse_sols = 0;
+        unsafe {
+            ffi::SCIPgetCountedSparseSols(
+                self.raw,
+                &mut vars,
+                &mut n_vars,
+                &mut sparse_sols,
+                &mut n_sparse_sols,
+            )
+        };
+
+        // the sparse solutions refer to the transformed variables, so report the original ones
+        let orig_vars: HashMap<*mut SCIP_Var, *mut SCIP_Var> = self
+            .orig_vars()
+            .into_iter()
+            .map(|var| (unsafe { ffi::SCIPvarGetTransVar(var) }, var))
+            .collect();
+        let vars: Vec<*mut SCIP_Var> = (0..n_vars as usize)
+            .map(|i| {
+                let var = unsafe { *vars.add(i) };
+                orig_vars.get(&var).copied().unwrap_or(var)
+            })
+            .collect();
+        let names: Vec<String> = vars
+            .iter()
+            .map(|&var| {
+                let name = unsafe { CStr::from_ptr(ffi::SCIPvarGetName(var)) };
+                name.to_str().unwrap().to_string()
+            })
+            .collect();
+        let objs: Vec<f64> = vars
+            .iter()
+            .map(|&var| unsafe { ffi::SCIPvarGetObj(var) })
+            .collect();
+        let obj_offset = unsafe { ffi::SCIPgetOrigObjoffset(self.raw) };
+
+        let limit = limit.unwrap_or(usize::MAX);
+        let mut sols = Vec::new();
+        let mut vals = vec![0; n_vars as usize];
+        for i in 0..n_sparse_sols as usize {
+            let sparse_sol = unsafe { *sparse_sols.add(i) };
+            unsafe { ffi::SCIPsparseSolGetFirstSol(sparse_sol, vals.as_mut_ptr(), n_vars) };
+            loop {
+                if sols.len() >= limit {
+                    return sols;
+                }
+                let values: Vec<f64> = vals.iter().map(|&val| val as f64).collect();
+                let obj_val = obj_offset
+                    + objs
+                        .iter()
+                        .zip(&values)
+                        .map(|(obj, val)| obj * val)
+                        .sum::<f64>();
+                sols.push(SolutionValues {
+                    obj_val,
+                    names: names.clone(),
+                    values,
+                });
+                let has_next =
+                    unsafe { ffi::SCIPsparseSolGetNextSol(sparse_sol, vals.as_mut_ptr(), n_vars) };
+                if has_next == 0 {
+                    break;
+                }
+            }
+        }
+        sols
+    }
+
+    pub(crate) fn solve_concurrent(&self) -> Result<(), Retcode> {
+        scip_call!(ffi::SCIPsolveConcurrent(self.raw));
+        // without a task processing interface, SCIP returns without solving the problem
+        if self.stage() == Stage::Problem {
+            return self.solve();
+        }
+        Ok(())
+    }
+
+    pub(crate) fn is_dual_sol_available(&self) -> bool {
+        (unsafe { ffi::SCIPisDualSolAvailable(self.raw, 0) }) != 0
+    }
+
+    pub(crate) fn lp_duals(&self) -> Result<Vec<f64>, Retcode> {
+        self.conss(false)
+            .into_iter()
+            .map(|cons| {
+                let mut dual = 0.0;
+                let mut bound_cons = 0;
+                scip_call!(ffi::SCIPgetDualSolVal(
+                    self.raw,
+                    cons,
+                    &mut dual,
+                    &mut bound_cons
+                ));
+                Ok(dual)
+            })
+            .collect()
+    }
+
+    /// Returns the reduced costs of the original variables in the final LP, in the sense of the original objective.
+    ///
+    /// `SCIPgetVarRedcost` needs the LP of the current node, which no longer exists once the problem is solved, so
+    /// the reduced costs of the columns of the transformed variables are computed from the dual values of the rows,
+    /// which are kept.
+    pub(crate) fn lp_redcosts(&self) -> Vec<f64> {
+        let obj_sense = unsafe { ffi::SCIPgetObjsense(self.raw) } as f64;
+        self.orig_vars()
+            .into_iter()
+            .map(|var| {
+                let trans_var = unsafe { ffi::SCIPvarGetTransVar(var) };
+                if trans_var.is_null()
+                    || unsafe { ffi::SCIPvarGetStatus(trans_var) }
+                        != ffi::SCIP_Varstatus_SCIP_VARSTATUS_COLUMN
+                {
+                    // fixed or aggregated variables have no reduced cost
+                    return 0.0;
+                }
+                let col = unsafe { ffi::SCIPvarGetCol(trans_var) };
+                obj_sense * unsafe { ffi::SCIPcolCalcRedcost(col, std::ptr::null_mut()) }
+            })
+            .collect()
+    }
+
+    pub(crate) fn interrupt_solve(&self) -> Result<(), Retcode> {
+        scip_call!(ffi::SCIPinterruptSolve(self.raw));
+        Ok(())
+    }
+
     pub(crate) fn n_sols(&self) -> usize {
         unsafe { ffi::SCIPgetNSols(self.raw) as usize }
     }
@@ -254,6 +783,10 @@ impl ScipPtr {
         unsafe { ffi::SCIPgetDualbound(self.raw) }
     }
 
+    pub(crate) fn gap(&self) -> f64 {
+        unsafe { ffi::SCIPgetGap(self.raw) }
+    }
+
     pub(crate) fn create_var(
         &self,
         lb: f64,
@@ -262,7 +795,7 @@ impl ScipPtr {
         name: &str,
         var_type: VarType,
     ) -> Result<*mut SCIP_Var, Retcode> {
-        let name = CString::new(name).unwrap();
+        let name = c_string(name)?;
         let mut var_ptr = MaybeUninit::uninit();
         scip_call! { ffi::SCIPcreateVarBasic(
             self.raw,
@@ -286,7 +819,7 @@ impl ScipPtr {
         name: &str,
         var_type: VarType,
     ) -> Result<*mut SCIP_Var, Retcode> {
-        let name = CString::new(name).unwrap();
+        let name = c_string(name)?;
         let mut var_ptr = MaybeUninit::uninit();
         scip_call! { ffi::SCIPcreateVarBasic(
             self.raw,
@@ -314,7 +847,7 @@ impl ScipPtr {
         name: &str,
         var_type: VarType,
     ) -> Result<*mut SCIP_Var, Retcode> {
-        let name = CString::new(name).unwrap();
+        let name = c_string(name)?;
         let mut var_ptr = MaybeUninit::uninit();
         scip_call! { ffi::SCIPcreateVarBasic(
             self.raw,
@@ -343,7 +876,7 @@ impl ScipPtr {
         name: &str,
     ) -> Result<*mut SCIP_Cons, Retcode> {
         assert_eq!(vars.len(), coefs.len());
-        let c_name = CString::new(name).unwrap();
+        let c_name = c_string(name)?;
         let mut scip_cons = MaybeUninit::uninit();
         scip_call! { ffi::SCIPcreateConsBasicLinear(
             self.raw,
@@ -373,7 +906,7 @@ impl ScipPtr {
         vars: Vec<&Variable>,
         name: &str,
     ) -> Result<*mut SCIP_Cons, Retcode> {
-        let c_name = CString::new(name).unwrap();
+        let c_name = c_string(name)?;
         let mut scip_cons = MaybeUninit::uninit();
         scip_call! { ffi::SCIPcreateConsBasicSetpart(
             self.raw,
@@ -396,7 +929,7 @@ impl ScipPtr {
         vars: Vec<&Variable>,
         name: &str,
     ) -> Result<*mut SCIP_Cons, Retcode> {
-        let c_name = CString::new(name).unwrap();
+        let c_name = c_string(name)?;
         let mut scip_cons = MaybeUninit::uninit();
         scip_call! { ffi::SCIPcreateConsBasicSetcover(
             self.raw,
@@ -436,7 +969,7 @@ impl ScipPtr {
             "Number of quadratic terms exceeds SCIP capabilities"
         );
 
-        let c_name = CString::new(name).unwrap();
+        let c_name = c_string(name)?;
         let mut scip_cons = MaybeUninit::uninit();
 
         let get_ptrs = |vars: Vec<&Variable>| {
@@ -473,7 +1006,7 @@ impl ScipPtr {
         vars: Vec<&Variable>,
         name: &str,
     ) -> Result<*mut SCIP_Cons, Retcode> {
-        let c_name = CString::new(name).unwrap();
+        let c_name = c_string(name)?;
         let mut scip_cons = MaybeUninit::uninit();
         scip_call! { ffi::SCIPcreateConsBasicSetpack(
             self.raw,
@@ -497,7 +1030,7 @@ impl ScipPtr {
         cardinality: usize,
         name: &str,
     ) -> Result<*mut SCIP_Cons, Retcode> {
-        let c_name = CString::new(name).unwrap();
+        let c_name = c_string(name)?;
         let mut scip_cons = MaybeUninit::uninit();
         scip_call! { ffi::SCIPcreateConsBasicCardinality(
             self.raw,
@@ -536,7 +1069,7 @@ impl ScipPtr {
         name: &str,
     ) -> Result<*mut SCIP_Cons, Retcode> {
         assert_eq!(vars.len(), coefs.len());
-        let c_name = CString::new(name).unwrap();
+        let c_name = c_string(name)?;
         let mut scip_cons = MaybeUninit::uninit();
 
         scip_call! { ffi::SCIPcreateConsBasicIndicator(
@@ -684,8 +1217,8 @@ impl ScipPtr {
             Retcode::Okay.into()
         }
 
-        let c_name = CString::new(name).unwrap();
-        let c_desc = CString::new(desc).unwrap();
+        let c_name = c_string(name)?;
+        let c_desc = c_string(desc)?;
         let eventhdlr_ptr = Box::into_raw(Box::new(eventhdlr));
 
         unsafe {
@@ -717,8 +1250,8 @@ impl ScipPtr {
         maxbounddist: f64,
         rule: Box<dyn BranchRule>,
     ) -> Result<(), Retcode> {
-        let c_name = CString::new(name).unwrap();
-        let c_desc = CString::new(desc).unwrap();
+        let c_name = c_string(name)?;
+        let c_desc = c_string(desc)?;
 
         // TODO: Add rest of branching rule plugin callbacks
 
@@ -807,8 +1340,8 @@ impl ScipPtr {
         delay: bool,
         pricer: Box<dyn Pricer>,
     ) -> Result<(), Retcode> {
-        let c_name = CString::new(name).unwrap();
-        let c_desc = CString::new(desc).unwrap();
+        let c_name = c_string(name)?;
+        let c_desc = c_string(desc)?;
 
         pub(crate) fn call_pricer(
             scip: *mut ffi::SCIP,
@@ -932,8 +1465,8 @@ impl ScipPtr {
         usessubscip: bool,
         heur: Box<dyn Heuristic>,
     ) -> Result<(), Retcode> {
-        let c_name = CString::new(name).unwrap();
-        let c_desc = CString::new(desc).unwrap();
+        let c_name = c_string(name)?;
+        let c_desc = c_string(desc)?;
 
         extern "C" fn heurexec(
             scip: *mut ffi::SCIP,
@@ -1009,6 +1542,78 @@ impl ScipPtr {
         Ok(())
     }
 
+    pub(crate) fn include_display_column(
+        &self,
+        name: &str,
+        desc: &str,
+        header: &str,
+        width: usize,
+        priority: i32,
+        position: i32,
+        stripline: bool,
+        status: DisplayStatus,
+        column: Box<dyn DisplayColumn>,
+    ) -> Result<(), Retcode> {
+        let c_name = c_string(name)?;
+        let c_desc = c_string(desc)?;
+        let c_header = c_string(header)?;
+
+        extern "C" fn dispoutput(
+            scip: *mut ffi::SCIP,
+            disp: *mut ffi::SCIP_DISP,
+            file: *mut ffi::FILE,
+        ) -> ffi::SCIP_RETCODE {
+            let data_ptr = unsafe { ffi::SCIPdispGetData(disp) };
+            assert!(!data_ptr.is_null());
+            let column_ptr = data_ptr as *mut Box<dyn DisplayColumn>;
+
+            let width = unsafe { ffi::SCIPdispGetWidth(disp) }.max(0) as usize;
+            let model = Model {
+                scip: Rc::new(ScipPtr::from_raw(scip, true)),
+                state: Solving,
+            };
+            let output = unsafe { (*column_ptr).output(model) };
+            let output = CString::new(fit_to_width(&output, width).replace('\0', "")).unwrap();
+            unsafe { ffi::SCIPinfoMessage(scip, file, c"%s".as_ptr(), output.as_ptr()) };
+            Retcode::Okay.into()
+        }
+
+        extern "C" fn dispfree(
+            _scip: *mut ffi::SCIP,
+            disp: *mut ffi::SCIP_DISP,
+        ) -> ffi::SCIP_Retcode {
+            let data_ptr = unsafe { ffi::SCIPdispGetData(disp) };
+            assert!(!data_ptr.is_null());
+            drop(unsafe { Box::from_raw(data_ptr as *mut Box<dyn DisplayColumn>) });
+            Retcode::Okay.into()
+        }
+
+        let ptr = Box::into_raw(Box::new(column));
+        let disp_faker = ptr as *mut ffi::SCIP_DISPDATA;
+
+        scip_call!(ffi::SCIPincludeDisp(
+            self.raw,
+            c_name.as_ptr(),
+            c_desc.as_ptr(),
+            c_header.as_ptr(),
+            status.into(),
+            None,
+            Some(dispfree),
+            None,
+            None,
+            None,
+            None,
+            Some(dispoutput),
+            disp_faker,
+            width as c_int,
+            priority,
+            position,
+            stripline.into(),
+        ));
+
+        Ok(())
+    }
+
     pub(crate) fn include_separator(
         &self,
         name: &str,
@@ -1020,8 +1625,8 @@ impl ScipPtr {
         delay: bool,
         separator: Box<dyn Separator>,
     ) -> Result<(), Retcode> {
-        let c_name = CString::new(name).unwrap();
-        let c_desc = CString::new(desc).unwrap();
+        let c_name = c_string(name)?;
+        let c_desc = c_string(desc)?;
 
         extern "C" fn sepexeclp(
             scip: *mut ffi::SCIP,
@@ -1094,6 +1699,120 @@ impl ScipPtr {
         Ok(())
     }
 
+    pub(crate) fn create_benders_default(
+        &self,
+        subproblems: Vec<Rc<ScipPtr>>,
+    ) -> Result<(), Retcode> {
+        let mut raw_subproblems: Vec<*mut ffi::SCIP> =
+            subproblems.iter().map(|scip| scip.raw).collect();
+        scip_call!(ffi::SCIPcreateBendersDefault(
+            self.raw,
+            raw_subproblems.as_mut_ptr(),
+            raw_subproblems.len() as c_int,
+        ));
+        self.benders_subproblems.borrow_mut().extend(subproblems);
+        Ok(())
+    }
+
+    /// Returns whether a Benders' subproblem of the instance is still referenced elsewhere, e.g. by its variables.
+    pub(crate) fn has_shared_benders_subproblems(&self) -> bool {
+        self.benders_subproblems
+            .borrow()
+            .iter()
+            .any(|scip| Rc::strong_count(scip) > 1 || Rc::weak_count(scip) > 0)
+    }
+
+    pub(crate) fn include_benders_cut(
+        &self,
+        name: &str,
+        desc: &str,
+        priority: i32,
+        islpcut: bool,
+        cut: Box<dyn BendersCut>,
+    ) -> Result<(), Retcode> {
+        let c_benders = CString::new(DEFAULT_BENDERS).unwrap();
+        let benders = unsafe { ffi::SCIPfindBenders(self.raw, c_benders.as_ptr()) };
+        if benders.is_null() {
+            return Err(Retcode::InvalidCall);
+        }
+        let c_name = c_string(name)?;
+        let c_desc = c_string(desc)?;
+
+        extern "C" fn benderscutexec(
+            scip: *mut ffi::SCIP,
+            benders: *mut ffi::SCIP_BENDERS,
+            benderscut: *mut ffi::SCIP_BENDERSCUT,
+            sol: *mut SCIP_SOL,
+            probnumber: c_int,
+            enfo_type: ffi::SCIP_BENDERSENFOTYPE,
+            result: *mut ffi::SCIP_RESULT,
+        ) -> ffi::SCIP_Retcode {
+            let data_ptr = unsafe { ffi::SCIPbenderscutGetData(benderscut) };
+            assert!(!data_ptr.is_null());
+            let cut_ptr = data_ptr as *mut Box<dyn BendersCut>;
+
+            let scip = Rc::new(ScipPtr::from_raw(scip, true));
+            let model = Model {
+                scip: scip.clone(),
+                state: Solving,
+            };
+            let benders = SCIPBenders {
+                raw: benders,
+                scip: scip.clone(),
+            };
+            let sol = Solution {
+                raw: sol,
+                scip_ptr: scip,
+            };
+            let cut_res = unsafe {
+                (*cut_ptr).execute(model, benders, sol, probnumber as usize, enfo_type.into())
+            };
+
+            unsafe { *result = cut_res.into() };
+
+            Retcode::Okay.into()
+        }
+
+        extern "C" fn benderscutfree(
+            _scip: *mut ffi::SCIP,
+            benderscut: *mut ffi::SCIP_BENDERSCUT,
+        ) -> ffi::SCIP_Retcode {
+            let data_ptr = unsafe { ffi::SCIPbenderscutGetData(benderscut) };
+            assert!(!data_ptr.is_null());
+            drop(unsafe { Box::from_raw(data_ptr as *mut Box<dyn BendersCut>) });
+            Retcode::Okay.into()
+        }
+
+        let ptr = Box::into_raw(Box::new(cut));
+        let cut_faker = ptr as *mut ffi::SCIP_BENDERSCUTDATA;
+
+        let mut benderscut = std::ptr::null_mut();
+        let res = unsafe {
+            ffi::SCIPincludeBenderscutBasic(
+                self.raw,
+                benders,
+                &mut benderscut,
+                c_name.as_ptr(),
+                c_desc.as_ptr(),
+                priority,
+                islpcut.into(),
+                Some(benderscutexec),
+                cut_faker,
+            )
+        };
+        if Retcode::from(res) != Retcode::Okay {
+            drop(unsafe { Box::from_raw(ptr) });
+            return Err(Retcode::from(res));
+        }
+        scip_call!(ffi::SCIPsetBenderscutFree(
+            self.raw,
+            benderscut,
+            Some(benderscutfree)
+        ));
+
+        Ok(())
+    }
+
     pub(crate) fn add_cons_coef(
         &self,
         cons: &Constraint,
@@ -1156,6 +1875,104 @@ impl ScipPtr {
         unsafe { ffi::SCIPgetNLPIterations(self.raw) as usize }
     }
 
+    pub(crate) fn primal_dual_integral(&self) -> f64 {
+        unsafe { ffi::SCIPgetPrimalDualIntegral(self.raw) }
+    }
+
+    pub(crate) fn root_dual_bound(&self) -> f64 {
+        unsafe { ffi::SCIPgetDualboundRoot(self.raw) }
+    }
+
+    pub(crate) fn first_lp_time(&self) -> f64 {
+        unsafe { ffi::SCIPgetFirstLPTime(self.raw) }
+    }
+
+    pub(crate) fn presolving_time(&self) -> f64 {
+        unsafe { ffi::SCIPgetPresolvingTime(self.raw) }
+    }
+
+    pub(crate) fn max_depth(&self) -> usize {
+        let depth = unsafe { ffi::SCIPgetMaxTotalDepth(self.raw) };
+        depth.max(0) as usize
+    }
+
+    pub(crate) fn n_runs(&self) -> usize {
+        unsafe { ffi::SCIPgetNRuns(self.raw) as usize }
+    }
+
+    pub(crate) fn n_sols_found(&self) -> usize {
+        unsafe { ffi::SCIPgetNSolsFound(self.raw) as usize }
+    }
+
+    pub(crate) fn n_cuts_applied(&self) -> usize {
+        unsafe { ffi::SCIPgetNCutsApplied(self.raw) as usize }
+    }
+
+    /// Returns the name and number of applied cuts of every separator
+    pub(crate) fn separator_stats(&self) -> Vec<(String, usize)> {
+        let n_sepas = unsafe { ffi::SCIPgetNSepas(self.raw) } as usize;
+        let sepas = unsafe { ffi::SCIPgetSepas(self.raw) };
+        let mut stats = Vec::with_capacity(n_sepas);
+        for i in 0..n_sepas {
+            let sepa = unsafe { *sepas.add(i) };
+            let name = unsafe { CStr::from_ptr(ffi::SCIPsepaGetName(sepa)) };
+            let n_cuts = unsafe { ffi::SCIPsepaGetNCutsApplied(sepa) } as usize;
+            stats.push((name.to_str().unwrap().to_string(), n_cuts));
+        }
+        stats
+    }
+
+    /// Returns the name, number of found solutions and number of found best solutions of every heuristic
+    pub(crate) fn heuristic_stats(&self) -> Vec<(String, usize, usize)> {
+        let n_heurs = unsafe { ffi::SCIPgetNHeurs(self.raw) } as usize;
+        let heurs = unsafe { ffi::SCIPgetHeurs(self.raw) };
+        let mut stats = Vec::with_capacity(n_heurs);
+        for i in 0..n_heurs {
+            let heur = unsafe { *heurs.add(i) };
+            let name = unsafe { CStr::from_ptr(ffi::SCIPheurGetName(heur)) };
+            let n_sols = unsafe { ffi::SCIPheurGetNSolsFound(heur) } as usize;
+            let n_best_sols = unsafe { ffi::SCIPheurGetNBestSolsFound(heur) } as usize;
+            stats.push((name.to_str().unwrap().to_string(), n_sols, n_best_sols));
+        }
+        stats
+    }
+
+    /// Prints SCIP's statistics to a temporary message handler and returns the captured output
+    pub(crate) fn statistics_string(&self) -> Result<String, Retcode> {
+        let output = std::rc::Rc::new(RefCell::new(String::new()));
+        let captured = output.clone();
+        let handler: Box<dyn MessageHandler> = Box::new(move |level: MessageLevel, msg: &str| {
+            if level == MessageLevel::Info {
+                let mut captured = captured.borrow_mut();
+                captured.push_str(msg);
+                captured.push('\n');
+            }
+        });
+        let mut messagehdlr =
+            Self::create_message_handler(std::rc::Rc::new(RefCell::new(handler)))?;
+
+        // keep the handler of the model alive while it is replaced
+        let mut previous = unsafe { ffi::SCIPgetMessagehdlr(self.raw) };
+        unsafe { ffi::SCIPmessagehdlrCapture(previous) };
+        let mut retcode = unsafe { ffi::SCIPsetMessagehdlr(self.raw, messagehdlr) };
+        if retcode == ffi::SCIP_Retcode_SCIP_OKAY {
+            retcode = unsafe { ffi::SCIPprintStatistics(self.raw, std::ptr::null_mut()) };
+        }
+        let restored = unsafe { ffi::SCIPsetMessagehdlr(self.raw, previous) };
+        unsafe { ffi::SCIPmessagehdlrRelease(&mut previous) };
+        // frees the temporary handler, which flushes its last line
+        unsafe { ffi::SCIPmessagehdlrRelease(&mut messagehdlr) };
+
+        if retcode != ffi::SCIP_Retcode_SCIP_OKAY {
+            return Err(Retcode::from(retcode));
+        }
+        if restored != ffi::SCIP_Retcode_SCIP_OKAY {
+            return Err(Retcode::from(restored));
+        }
+        let output = output.take();
+        Ok(output)
+    }
+
     pub(crate) fn focus_node(&self) -> Option<*mut SCIP_NODE> {
         let ptr = unsafe { ffi::SCIPgetFocusNode(self.raw) };
         if ptr.is_null() {
@@ -1204,6 +2021,227 @@ impl ScipPtr {
         ));
         Ok(infeasible != 0)
     }
+
+    pub(crate) fn lp_obj_val(&self) -> f64 {
+        unsafe { ffi::SCIPgetLPObjval(self.raw) }
+    }
+
+    pub(crate) fn lp_sol_stat(&self) -> LpSolStat {
+        LpSolStat::from(unsafe { ffi::SCIPgetLPSolstat(self.raw) })
+    }
+
+    pub(crate) fn is_lp_sol_basic(&self) -> bool {
+        unsafe { ffi::SCIPisLPSolBasic(self.raw) != 0 }
+    }
+
+    pub(crate) fn lp_cols(&self) -> Vec<*mut ffi::SCIP_COL> {
+        let n_cols = unsafe { ffi::SCIPgetNLPCols(self.raw) } as usize;
+        let cols = unsafe { ffi::SCIPgetLPCols(self.raw) };
+        (0..n_cols).map(|i| unsafe { *cols.add(i) }).collect()
+    }
+
+    pub(crate) fn lp_rows(&self) -> Vec<*mut ffi::SCIP_ROW> {
+        let n_rows = unsafe { ffi::SCIPgetNLPRows(self.raw) } as usize;
+        let rows = unsafe { ffi::SCIPgetLPRows(self.raw) };
+        (0..n_rows).map(|i| unsafe { *rows.add(i) }).collect()
+    }
+
+    pub(crate) fn lp_basis_ind(&self) -> Result<Vec<BasisIndex>, Retcode> {
+        let n_rows = unsafe { ffi::SCIPgetNLPRows(self.raw) } as usize;
+        let mut basis_ind = vec![0; n_rows];
+        scip_call!(ffi::SCIPgetLPBasisInd(self.raw, basis_ind.as_mut_ptr()));
+        Ok(basis_ind.into_iter().map(BasisIndex::from).collect())
+    }
+
+    pub(crate) fn lp_binv_row(&self, row: usize) -> Result<Vec<f64>, Retcode> {
+        let n_rows = unsafe { ffi::SCIPgetNLPRows(self.raw) } as usize;
+        if row >= n_rows {
+            return Err(Retcode::InvalidData);
+        }
+        let mut coefs = vec![0.0; n_rows];
+        scip_call!(ffi::SCIPgetLPBInvRow(
+            self.raw,
+            row as c_int,
+            coefs.as_mut_ptr(),
+            std::ptr::null_mut(),
+            std::ptr::null_mut()
+        ));
+        Ok(coefs)
+    }
+
+    pub(crate) fn lp_binva_row(&self, row: usize) -> Result<Vec<f64>, Retcode> {
+        let n_rows = unsafe { ffi::SCIPgetNLPRows(self.raw) } as usize;
+        if row >= n_rows {
+            return Err(Retcode::InvalidData);
+        }
+        let n_cols = unsafe { ffi::SCIPgetNLPCols(self.raw) } as usize;
+        let mut coefs = vec![0.0; n_cols];
+        scip_call!(ffi::SCIPgetLPBInvARow(
+            self.raw,
+            row as c_int,
+            std::ptr::null_mut(),
+            coefs.as_mut_ptr(),
+            std::ptr::null_mut(),
+            std::ptr::null_mut()
+        ));
+        Ok(coefs)
+    }
+
+    pub(crate) fn start_dive(&self) -> Result<(), Retcode> {
+        scip_call!(ffi::SCIPstartDive(self.raw));
+        Ok(())
+    }
+
+    pub(crate) fn end_dive(&self) -> Result<(), Retcode> {
+        scip_call!(ffi::SCIPendDive(self.raw));
+        Ok(())
+    }
+
+    pub(crate) fn in_dive(&self) -> bool {
+        unsafe { ffi::SCIPinDive(self.raw) != 0 }
+    }
+
+    pub(crate) fn chg_var_lb_dive(&self, var: &Variable, lb: f64) -> Result<(), Retcode> {
+        scip_call!(ffi::SCIPchgVarLbDive(self.raw, var.raw, lb));
+        Ok(())
+    }
+
+    pub(crate) fn chg_var_ub_dive(&self, var: &Variable, ub: f64) -> Result<(), Retcode> {
+        scip_call!(ffi::SCIPchgVarUbDive(self.raw, var.raw, ub));
+        Ok(())
+    }
+
+    pub(crate) fn chg_var_obj_dive(&self, var: &Variable, obj: f64) -> Result<(), Retcode> {
+        scip_call!(ffi::SCIPchgVarObjDive(self.raw, var.raw, obj));
+        Ok(())
+    }
+
+    pub(crate) fn var_lb_dive(&self, var: &Variable) -> f64 {
+        unsafe { ffi::SCIPgetVarLbDive(self.raw, var.raw) }
+    }
+
+    pub(crate) fn var_ub_dive(&self, var: &Variable) -> f64 {
+        unsafe { ffi::SCIPgetVarUbDive(self.raw, var.raw) }
+    }
+
+    pub(crate) fn var_obj_dive(&self, var: &Variable) -> f64 {
+        unsafe { ffi::SCIPgetVarObjDive(self.raw, var.raw) }
+    }
+
+    pub(crate) fn chg_row_lhs_dive(&self, row: &Row, lhs: f64) -> Result<(), Retcode> {
+        scip_call!(ffi::SCIPchgRowLhsDive(self.raw, row.raw, lhs));
+        Ok(())
+    }
+
+    pub(crate) fn chg_row_rhs_dive(&self, row: &Row, rhs: f64) -> Result<(), Retcode> {
+        scip_call!(ffi::SCIPchgRowRhsDive(self.raw, row.raw, rhs));
+        Ok(())
+    }
+
+    pub(crate) fn chg_cutoff_bound_dive(&self, cutoff_bound: f64) -> Result<(), Retcode> {
+        scip_call!(ffi::SCIPchgCutoffboundDive(self.raw, cutoff_bound));
+        Ok(())
+    }
+
+    pub(crate) fn solve_dive_lp(
+        &self,
+        iteration_limit: Option<usize>,
+    ) -> Result<LpSolveResult, Retcode> {
+        let mut lp_error = 0;
+        let mut cutoff = 0;
+        let itlim = iteration_limit.map_or(-1, |limit| limit.min(c_int::MAX as usize) as c_int);
+        scip_call!(ffi::SCIPsolveDiveLP(
+            self.raw,
+            itlim,
+            &mut lp_error,
+            &mut cutoff
+        ));
+        Ok(LpSolveResult {
+            lp_error: lp_error != 0,
+            cutoff: cutoff != 0,
+        })
+    }
+
+    pub(crate) fn start_probing(&self) -> Result<(), Retcode> {
+        scip_call!(ffi::SCIPstartProbing(self.raw));
+        Ok(())
+    }
+
+    pub(crate) fn end_probing(&self) -> Result<(), Retcode> {
+        scip_call!(ffi::SCIPendProbing(self.raw));
+        Ok(())
+    }
+
+    pub(crate) fn in_probing(&self) -> bool {
+        unsafe { ffi::SCIPinProbing(self.raw) != 0 }
+    }
+
+    pub(crate) fn new_probing_node(&self) -> Result<(), Retcode> {
+        scip_call!(ffi::SCIPnewProbingNode(self.raw));
+        Ok(())
+    }
+
+    pub(crate) fn probing_depth(&self) -> usize {
+        unsafe { ffi::SCIPgetProbingDepth(self.raw) as usize }
+    }
+
+    pub(crate) fn fix_var_probing(&self, var: &Variable, val: f64) -> Result<(), Retcode> {
+        scip_call!(ffi::SCIPfixVarProbing(self.raw, var.raw, val));
+        Ok(())
+    }
+
+    pub(crate) fn chg_var_lb_probing(&self, var: &Variable, lb: f64) -> Result<(), Retcode> {
+        scip_call!(ffi::SCIPchgVarLbProbing(self.raw, var.raw, lb));
+        Ok(())
+    }
+
+    pub(crate) fn chg_var_ub_probing(&self, var: &Variable, ub: f64) -> Result<(), Retcode> {
+        scip_call!(ffi::SCIPchgVarUbProbing(self.raw, var.raw, ub));
+        Ok(())
+    }
+
+    pub(crate) fn propagate_probing(
+        &self,
+        max_rounds: Option<usize>,
+    ) -> Result<PropagationResult, Retcode> {
+        let mut cutoff = 0;
+        let mut n_dom_reds = 0;
+        let max_rounds = max_rounds.map_or(-1, |rounds| rounds.min(c_int::MAX as usize) as c_int);
+        scip_call!(ffi::SCIPpropagateProbing(
+            self.raw,
+            max_rounds,
+            &mut cutoff,
+            &mut n_dom_reds
+        ));
+        Ok(PropagationResult {
+            cutoff: cutoff != 0,
+            n_dom_reds: n_dom_reds as usize,
+        })
+    }
+
+    pub(crate) fn solve_probing_lp(
+        &self,
+        iteration_limit: Option<usize>,
+    ) -> Result<LpSolveResult, Retcode> {
+        let mut lp_error = 0;
+        let mut cutoff = 0;
+        let itlim = iteration_limit.map_or(-1, |limit| limit.min(c_int::MAX as usize) as c_int);
+        scip_call!(ffi::SCIPsolveProbingLP(
+            self.raw,
+            itlim,
+            &mut lp_error,
+            &mut cutoff
+        ));
+        Ok(LpSolveResult {
+            lp_error: lp_error != 0,
+            cutoff: cutoff != 0,
+        })
+    }
+
+    pub(crate) fn backtrack_probing(&self, depth: usize) -> Result<(), Retcode> {
+        scip_call!(ffi::SCIPbacktrackProbing(self.raw, depth as c_int));
+        Ok(())
+    }
 }
 
 impl Drop for ScipPtr {
@@ -1,9 +1,9 @@
 use std::fmt;
-use std::rc::Rc;
 
 use crate::scip::ScipPtr;
 use crate::variable::Variable;
 use crate::{ffi, scip_call_panic};
+use std::rc::Rc;
 
 /// A wrapper for a SCIP solution.
 #[derive(Clone)]
@@ -52,6 +52,29 @@ impl Solution {
         map
     }
 
+    /// Returns an owned snapshot of the solution values of the original problem's variables.
+    ///
+    /// Unlike `Solution`, the returned `SolutionValues` does not hold any reference to the SCIP
+    /// instance, so it can be sent across threads and outlives the model (e.g. after `free_transform`).
+    pub fn to_owned_values(&self) -> SolutionValues {
+        let vars = unsafe { ffi::SCIPgetOrigVars(self.scip_ptr.raw) };
+        let n_vars = unsafe { ffi::SCIPgetNOrigVars(self.scip_ptr.raw) };
+        let mut names = Vec::with_capacity(n_vars as usize);
+        let mut values = Vec::with_capacity(n_vars as usize);
+        for i in 0..n_vars {
+            let var = unsafe { *vars.offset(i as isize) };
+            let name_ptr = unsafe { ffi::SCIPvarGetName(var) };
+            let name = unsafe { std::ffi::CStr::from_ptr(name_ptr).to_str().unwrap() };
+            names.push(name.to_string());
+            values.push(unsafe { ffi::SCIPgetSolVal(self.scip_ptr.raw, self.raw, var) });
+        }
+        SolutionValues {
+            obj_val: self.obj_val(),
+            names,
+            values,
+        }
+    }
+
     /// Returns the solution as a var-id to value map.
     pub fn as_id_map(&self) -> std::collections::HashMap<i32, f64> {
         let vars = unsafe { ffi::SCIPgetVars(self.scip_ptr.raw) };
@@ -92,6 +115,66 @@ impl fmt::Debug for Solution {
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for Solution {
+    /// Serializes the solution as its objective value and a map from the names of the original problem's variables
+    /// to their values, including zero values.
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let owned = self.to_owned_values();
+        let values: std::collections::BTreeMap<String, f64> =
+            owned.names.into_iter().zip(owned.values).collect();
+        let mut state = serializer.serialize_struct("Solution", 2)?;
+        state.serialize_field("obj_val", &owned.obj_val)?;
+        state.serialize_field("values", &values)?;
+        state.end()
+    }
+}
+
+/// An owned snapshot of a solution, detached from the SCIP instance it was taken from.
+///
+/// Values are indexed by the position of the variable in the original problem, i.e. the order in
+/// which the variables were added to the model.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SolutionValues {
+    /// The objective value of the solution.
+    pub obj_val: f64,
+    /// The names of the variables.
+    pub names: Vec<String>,
+    /// The values of the variables.
+    pub values: Vec<f64>,
+}
+
+impl SolutionValues {
+    /// Returns the number of variables in the snapshot.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns whether the snapshot contains no variables.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Returns the value of the variable with the given name, if it exists.
+    pub fn val_by_name(&self, name: &str) -> Option<f64> {
+        self.names
+            .iter()
+            .position(|n| n == name)
+            .map(|i| self.values[i])
+    }
+
+    /// Returns an iterator over the (name, value) pairs of the snapshot.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, f64)> {
+        self.names
+            .iter()
+            .map(String::as_str)
+            .zip(self.values.iter().copied())
+    }
+}
+
 /// Represents and error that can occur when adding a solution.
 #[derive(Debug, PartialEq, Eq)]
 pub enum SolError {
@@ -137,4 +220,69 @@ mod tests {
         assert_eq!(sol_id_map.get(&0).unwrap(), &40.);
         assert_eq!(sol_id_map.get(&1).unwrap(), &20.);
     }
+
+    #[test]
+    fn owned_values() {
+        fn assert_send_sync<T: Send + Sync + Clone>() {}
+        assert_send_sync::<SolutionValues>();
+
+        let model = Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .read_prob("data/test/simple.lp")
+            .unwrap()
+            .solve();
+
+        let values = model.best_sol().unwrap().to_owned_values();
+        let model = model.free_transform();
+        drop(model);
+
+        let values = std::thread::spawn(move || values).join().unwrap();
+        assert_eq!(values.len(), 2);
+        assert_eq!(values.obj_val, 200.);
+        assert_eq!(values.names, vec!["x1", "x2"]);
+        assert_eq!(values.values, vec![40., 20.]);
+        assert_eq!(values.val_by_name("x2"), Some(20.));
+        assert_eq!(values.val_by_name("x3"), None);
+    }
+
+    #[test]
+    fn start_sol_from_values() {
+        let model = Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .read_prob("data/test/simple.lp")
+            .unwrap()
+            .solve();
+        let values = model.best_sol().unwrap().to_owned_values();
+
+        let mut new_model = Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .read_prob("data/test/simple.lp")
+            .unwrap();
+        assert!(new_model.add_sol_values(&values).is_ok());
+
+        let solved = new_model.solve();
+        assert_eq!(solved.status(), Status::Optimal);
+        assert_eq!(solved.obj_val(), 200.);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize_sol() {
+        let model = Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .read_prob("data/test/simple.lp")
+            .unwrap()
+            .solve();
+
+        let sol = model.best_sol().unwrap();
+        let json = serde_json::to_value(&sol).unwrap();
+        assert_eq!(json["obj_val"], 200.);
+        assert_eq!(json["values"]["x1"], 40.);
+        assert_eq!(json["values"]["x2"], 20.);
+        assert_eq!(json["values"].as_object().unwrap().len(), 2);
+    }
 }
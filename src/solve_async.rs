@@ -0,0 +1,281 @@
+use crate::{
+    Event, EventMask, Eventhdlr, InterruptHandle, Model, SCIPEventhdlr, Solved, Solving,
+    WithSolvingStats,
+};
+use std::sync::mpsc::{Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+
+/// A progress update of a solving process running on a background thread, see [`SolveHandle`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum SolveProgress {
+    /// A new incumbent solution was found.
+    Incumbent {
+        /// The objective value of the new incumbent.
+        obj_val: f64,
+        /// The solving time at which it was found.
+        solving_time: f64,
+    },
+    /// The primal or the dual bound changed.
+    Bounds {
+        /// The objective value of the best solution found so far.
+        primal_bound: f64,
+        /// The best bound proven so far.
+        dual_bound: f64,
+    },
+    /// A node of the branch-and-bound tree was solved.
+    Nodes {
+        /// The number of nodes processed so far.
+        n_nodes: usize,
+    },
+}
+
+/// A handle to a model being solved on a background thread, returned by
+/// [`Model::solve_async`](crate::Model::solve_async).
+///
+/// With the `tokio` feature enabled, the handle is also a `Future` resolving to the solved model.
+#[derive(Debug)]
+pub struct SolveHandle {
+    progress: Receiver<SolveProgress>,
+    interrupt: InterruptHandle,
+    thread: Option<JoinHandle<SendModel<Solved>>>,
+    #[cfg(feature = "tokio")]
+    done: tokio::sync::oneshot::Receiver<()>,
+}
+
+impl SolveHandle {
+    /// Returns the receiving end of the progress updates. The channel is disconnected once the solving process ends.
+    pub fn progress(&self) -> &Receiver<SolveProgress> {
+        &self.progress
+    }
+
+    /// Returns a handle to interrupt the solving process.
+    pub fn interrupt_handle(&self) -> InterruptHandle {
+        self.interrupt.clone()
+    }
+
+    /// Returns whether the solving process has ended.
+    pub fn is_finished(&self) -> bool {
+        self.thread.as_ref().is_none_or(JoinHandle::is_finished)
+    }
+
+    /// Waits for the solving process to end and returns the solved model.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if solving panicked on the background thread.
+    pub fn join(mut self) -> Model<Solved> {
+        self.join_thread()
+    }
+
+    fn join_thread(&mut self) -> Model<Solved> {
+        let thread = self
+            .thread
+            .take()
+            .expect("Solving thread was already joined");
+        match thread.join() {
+            Ok(model) => model.into_inner(),
+            Err(panic) => std::panic::resume_unwind(panic),
+        }
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl std::future::Future for SolveHandle {
+    type Output = Model<Solved>;
+
+    fn poll(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Self::Output> {
+        // the sender is dropped without sending if solving panicked, joining then propagates the panic
+        match std::pin::Pin::new(&mut self.done).poll(cx) {
+            std::task::Poll::Ready(_) => std::task::Poll::Ready(self.join_thread()),
+            std::task::Poll::Pending => std::task::Poll::Pending,
+        }
+    }
+}
+
+/// A model that can be moved to another thread, created with [`Model::into_send`](crate::Model::into_send).
+///
+/// A model is not `Send`, as its SCIP instance is shared with its variables, constraints and solutions. It can only
+/// be moved to another thread if no other reference to the SCIP instance exists and no message handler or plugin
+/// provided by the user is included, except for plugins that are `Send` and included through the `_send` variants
+/// such as [`Model::include_heur_send`](crate::Model::include_heur_send). This is checked when the `SendModel` is
+/// created.
+#[derive(Debug)]
+pub struct SendModel<S>(pub(crate) Model<S>);
+
+// the model is the only owner of its SCIP instance, see `Model::into_send`
+unsafe impl<S> Send for SendModel<S> {}
+
+impl<S> SendModel<S> {
+    /// Returns the model, to be used on the thread it was moved to.
+    pub fn into_inner(self) -> Model<S> {
+        self.0
+    }
+}
+
+/// The sending end of the progress updates, shared with the solving thread so it can disconnect the channel once the
+/// solving process ends (the event handler itself lives as long as the SCIP instance).
+pub(crate) type ProgressSender = Arc<Mutex<Option<Sender<SolveProgress>>>>;
+
+/// Event handler that reports the progress of the solving process to a `SolveHandle`.
+pub(crate) struct ProgressEventhdlr {
+    sender: ProgressSender,
+    bounds: (f64, f64),
+}
+
+impl ProgressEventhdlr {
+    pub(crate) const NAME: &'static str = "russcip_progress";
+
+    pub(crate) fn new(sender: ProgressSender) -> Self {
+        ProgressEventhdlr {
+            sender,
+            bounds: (f64::NAN, f64::NAN),
+        }
+    }
+
+    fn send(&self, progress: SolveProgress) {
+        // the receiver might have been dropped, in which case progress is not reported anymore
+        if let Some(sender) = self.sender.lock().unwrap().as_ref() {
+            let _ = sender.send(progress);
+        }
+    }
+}
+
+impl Eventhdlr for ProgressEventhdlr {
+    fn get_type(&self) -> EventMask {
+        EventMask::BEST_SOL_FOUND | EventMask::NODE_SOLVED
+    }
+
+    fn execute(&mut self, model: Model<Solving>, _eventhdlr: SCIPEventhdlr, event: Event) {
+        if event.event_type().matches(EventMask::BEST_SOL_FOUND) {
+            self.send(SolveProgress::Incumbent {
+                obj_val: model.obj_val(),
+                solving_time: model.solving_time(),
+            });
+        } else {
+            self.send(SolveProgress::Nodes {
+                n_nodes: model.n_nodes(),
+            });
+        }
+
+        let bounds = (model.obj_val(), model.best_bound());
+        if bounds != self.bounds {
+            self.bounds = bounds;
+            self.send(SolveProgress::Bounds {
+                primal_bound: bounds.0,
+                dual_bound: bounds.1,
+            });
+        }
+    }
+}
+
+pub(crate) fn spawn(
+    model: SendModel<crate::ProblemCreated>,
+    sender: ProgressSender,
+    progress: Receiver<SolveProgress>,
+    interrupt: InterruptHandle,
+) -> SolveHandle {
+    #[cfg(feature = "tokio")]
+    let (done_sender, done) = tokio::sync::oneshot::channel();
+    let thread = std::thread::Builder::new()
+        .name("scip-solve".to_string())
+        .spawn(move || {
+            let solved = SendModel(model.into_inner().solve());
+            sender.lock().unwrap().take();
+            #[cfg(feature = "tokio")]
+            let _ = done_sender.send(());
+            solved
+        })
+        .expect("Failed to spawn solving thread");
+    SolveHandle {
+        progress,
+        interrupt,
+        thread: Some(thread),
+        #[cfg(feature = "tokio")]
+        done,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn progress_and_join() {
+        let model = Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .read_prob("data/test/gen-ip054.mps")
+            .unwrap();
+
+        let handle = model.solve_async().unwrap();
+        let updates: Vec<SolveProgress> = handle.progress().iter().collect();
+
+        let solved = handle.join();
+        assert_eq!(solved.status(), Status::Optimal);
+
+        let last_incumbent = updates
+            .iter()
+            .rev()
+            .find_map(|update| match update {
+                SolveProgress::Incumbent { obj_val, .. } => Some(*obj_val),
+                _ => None,
+            })
+            .unwrap();
+        assert_eq!(last_incumbent, solved.obj_val());
+        assert!(updates
+            .iter()
+            .any(|update| matches!(update, SolveProgress::Nodes { .. })));
+    }
+
+    #[test]
+    fn interrupt() {
+        let model = Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .read_prob("data/test/gen-ip054.mps")
+            .unwrap();
+
+        let handle = model.solve_async().unwrap();
+        handle.interrupt_handle().interrupt();
+        let solved = handle.join();
+        assert!(matches!(
+            solved.status(),
+            Status::UserInterrupt | Status::Optimal
+        ));
+    }
+
+    #[test]
+    fn shared_model_is_rejected() {
+        let mut model = Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .create_prob("test");
+        let _x = model.add(var().bin().obj(1.0));
+
+        let err = model.solve_async().unwrap_err();
+        assert_eq!(err.retcode(), Retcode::InvalidCall);
+    }
+
+    #[cfg(feature = "tokio")]
+    #[test]
+    fn future() {
+        let model = Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .read_prob("data/test/simple.lp")
+            .unwrap();
+
+        let handle = model.solve_async().unwrap();
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        let solved = runtime.block_on(handle);
+        assert_eq!(solved.status(), Status::Optimal);
+    }
+}
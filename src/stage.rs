@@ -0,0 +1,153 @@
+use crate::ffi;
+use scip_sys::SCIP_Stage;
+
+/// An enum representing the stage of a SCIP instance, i.e. where it currently is in the solving process.
+///
+/// This is finer grained than the typestates of `Model`, e.g. a callback can be called in the `Presolving` or the
+/// `Solving` stage while the model is always in the `Solving` state.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Stage {
+    /// SCIP data structures are initialized, no problem exists.
+    Init,
+    /// The problem is being created and modified.
+    Problem,
+    /// The problem is being transformed into solving data space.
+    Transforming,
+    /// The problem was transformed into solving data space.
+    Transformed,
+    /// Presolving is initialized.
+    InitPresolve,
+    /// The problem is being presolved.
+    Presolving,
+    /// Presolving is exited.
+    ExitPresolve,
+    /// The problem was presolved.
+    Presolved,
+    /// The solving process data is being initialized.
+    InitSolve,
+    /// The problem is being solved.
+    Solving,
+    /// The problem was solved.
+    Solved,
+    /// The solving process data is being freed.
+    ExitSolve,
+    /// The transformed problem is being freed.
+    FreeTrans,
+    /// SCIP data structures are being freed.
+    Free,
+}
+
+impl From<SCIP_Stage> for Stage {
+    /// Converts a `SCIP_Stage` value to a `Stage` enum variant.
+    fn from(val: SCIP_Stage) -> Self {
+        match val {
+            ffi::SCIP_Stage_SCIP_STAGE_INIT => Stage::Init,
+            ffi::SCIP_Stage_SCIP_STAGE_PROBLEM => Stage::Problem,
+            ffi::SCIP_Stage_SCIP_STAGE_TRANSFORMING => Stage::Transforming,
+            ffi::SCIP_Stage_SCIP_STAGE_TRANSFORMED => Stage::Transformed,
+            ffi::SCIP_Stage_SCIP_STAGE_INITPRESOLVE => Stage::InitPresolve,
+            ffi::SCIP_Stage_SCIP_STAGE_PRESOLVING => Stage::Presolving,
+            ffi::SCIP_Stage_SCIP_STAGE_EXITPRESOLVE => Stage::ExitPresolve,
+            ffi::SCIP_Stage_SCIP_STAGE_PRESOLVED => Stage::Presolved,
+            ffi::SCIP_Stage_SCIP_STAGE_INITSOLVE => Stage::InitSolve,
+            ffi::SCIP_Stage_SCIP_STAGE_SOLVING => Stage::Solving,
+            ffi::SCIP_Stage_SCIP_STAGE_SOLVED => Stage::Solved,
+            ffi::SCIP_Stage_SCIP_STAGE_EXITSOLVE => Stage::ExitSolve,
+            ffi::SCIP_Stage_SCIP_STAGE_FREETRANS => Stage::FreeTrans,
+            ffi::SCIP_Stage_SCIP_STAGE_FREE => Stage::Free,
+            _ => panic!("Unknown SCIP stage {:?}", val),
+        }
+    }
+}
+
+/// Stages in which variables can be added to the problem.
+pub(crate) const ADD_VAR_STAGES: &[Stage] = &[
+    Stage::Problem,
+    Stage::Transformed,
+    Stage::InitPresolve,
+    Stage::Presolving,
+    Stage::ExitPresolve,
+    Stage::Presolved,
+    Stage::Solving,
+];
+
+/// Stages in which the problem is being created, e.g. for setting up a Benders' decomposition.
+pub(crate) const PROBLEM_STAGES: &[Stage] = &[Stage::Problem];
+
+/// Stages in which constraints can be added to the problem.
+pub(crate) const ADD_CONS_STAGES: &[Stage] = &[
+    Stage::Problem,
+    Stage::Transformed,
+    Stage::InitPresolve,
+    Stage::Presolving,
+    Stage::ExitPresolve,
+    Stage::Presolved,
+    Stage::InitSolve,
+    Stage::Solving,
+    Stage::ExitSolve,
+];
+
+/// Stages in which solutions can be created.
+pub(crate) const CREATE_SOL_STAGES: &[Stage] = &[
+    Stage::Problem,
+    Stage::Transforming,
+    Stage::InitPresolve,
+    Stage::Presolving,
+    Stage::ExitPresolve,
+    Stage::Presolved,
+    Stage::InitSolve,
+    Stage::Solving,
+];
+
+/// Stages in which solutions can be added.
+pub(crate) const ADD_SOL_STAGES: &[Stage] = &[
+    Stage::Problem,
+    Stage::Transformed,
+    Stage::InitPresolve,
+    Stage::Presolving,
+    Stage::ExitPresolve,
+    Stage::Presolved,
+    Stage::Solving,
+    Stage::FreeTrans,
+];
+
+/// Stages in which the problem can be solved.
+pub(crate) const SOLVE_STAGES: &[Stage] = &[
+    Stage::Problem,
+    Stage::Transformed,
+    Stage::Presolving,
+    Stage::Presolved,
+    Stage::Solving,
+    Stage::Solved,
+];
+
+/// Stages in which the branch-and-bound tree exists.
+pub(crate) const TREE_STAGES: &[Stage] = &[
+    Stage::Transformed,
+    Stage::InitPresolve,
+    Stage::Presolving,
+    Stage::ExitPresolve,
+    Stage::Presolved,
+    Stage::InitSolve,
+    Stage::Solving,
+    Stage::Solved,
+    Stage::ExitSolve,
+];
+
+/// Stages in which the statistics of the transformed problem are available, e.g. the number of applied cuts.
+pub(crate) const STATS_STAGES: &[Stage] = &[
+    Stage::Transformed,
+    Stage::InitPresolve,
+    Stage::Presolving,
+    Stage::ExitPresolve,
+    Stage::Presolved,
+    Stage::Solving,
+    Stage::Solved,
+];
+
+/// Stages in which the current node is being processed, e.g. for branching, pricing and separation.
+pub(crate) const SOLVING_STAGES: &[Stage] = &[Stage::Solving];
+
+/// Stages in which the problem is solved, e.g. for reading the dual solution.
+pub(crate) const SOLVED_STAGES: &[Stage] = &[Stage::Solved];
@@ -0,0 +1,146 @@
+use crate::Status;
+
+/// A summary of a solving run, useful for logging or shipping results to other services.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SolveSummary {
+    /// The status of the solving process.
+    pub status: Status,
+    /// The objective value of the best solution found (primal bound).
+    pub obj_val: f64,
+    /// The best bound (dual bound) proven so far.
+    pub best_bound: f64,
+    /// The relative gap between the primal and the dual bound.
+    pub gap: f64,
+    /// The number of nodes explored.
+    pub n_nodes: usize,
+    /// The total solving time in seconds.
+    pub solving_time: f64,
+    /// The number of LP iterations performed.
+    pub n_lp_iterations: usize,
+}
+
+/// Detailed statistics of a solving run, e.g. for benchmarking dashboards.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Statistics {
+    /// The status of the solving process.
+    pub status: Status,
+    /// The primal bound (objective value of the best solution found).
+    pub primal_bound: f64,
+    /// The dual bound proven so far.
+    pub dual_bound: f64,
+    /// The relative gap between the primal and the dual bound.
+    pub gap: f64,
+    /// The primal-dual integral.
+    pub primal_dual_integral: f64,
+    /// The dual bound of the root node.
+    pub root_dual_bound: f64,
+    /// The time needed to solve the first LP of the root node in seconds.
+    pub root_lp_time: f64,
+    /// The presolving time in seconds.
+    pub presolving_time: f64,
+    /// The total solving time in seconds.
+    pub solving_time: f64,
+    /// The number of nodes explored.
+    pub n_nodes: usize,
+    /// The number of LP iterations performed.
+    pub n_lp_iterations: usize,
+    /// The maximal depth of all processed nodes over all runs.
+    pub max_depth: usize,
+    /// The number of restarts.
+    pub n_restarts: usize,
+    /// The number of feasible solutions found.
+    pub n_sols_found: usize,
+    /// The total number of cuts applied to the LP.
+    pub n_cuts_applied: usize,
+    /// The statistics of every separator.
+    pub separators: Vec<SeparatorStats>,
+    /// The statistics of every primal heuristic.
+    pub heuristics: Vec<HeuristicStats>,
+}
+
+/// Statistics of a single separator.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SeparatorStats {
+    /// The name of the separator.
+    pub name: String,
+    /// The number of cuts from this separator applied to the LP.
+    pub n_cuts_applied: usize,
+}
+
+/// Statistics of a single primal heuristic.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct HeuristicStats {
+    /// The name of the heuristic.
+    pub name: String,
+    /// The number of feasible solutions found by this heuristic.
+    pub n_sols_found: usize,
+    /// The number of new best solutions found by this heuristic.
+    pub n_best_sols_found: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn solve_summary() {
+        let model = Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .read_prob("data/test/simple.lp")
+            .unwrap()
+            .solve();
+
+        let summary = model.summary();
+        assert_eq!(summary.status, Status::Optimal);
+        assert_eq!(summary.obj_val, 200.);
+        assert!((summary.best_bound - 200.).abs() < 1e-6);
+        assert_eq!(summary.gap, 0.);
+        assert_eq!(summary.n_nodes, model.n_nodes());
+        assert_eq!(summary.n_lp_iterations, model.n_lp_iterations());
+    }
+
+    #[test]
+    fn statistics() {
+        let model = Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .read_prob("data/test/gen-ip054.mps")
+            .unwrap()
+            .solve();
+
+        let stats = model.statistics();
+        assert_eq!(stats.status, Status::Optimal);
+        assert_eq!(stats.primal_bound, model.obj_val());
+        assert_eq!(stats.dual_bound, model.best_bound());
+        assert!(stats.primal_dual_integral >= 0.);
+        assert!(stats.presolving_time <= stats.solving_time);
+        assert_eq!(stats.n_nodes, model.n_nodes());
+        assert!(stats.n_sols_found >= 1);
+        assert!(!stats.separators.is_empty());
+        assert!(!stats.heuristics.is_empty());
+
+        let stats_str = model.statistics_string().unwrap();
+        assert!(stats_str.contains("Presolvers"));
+        assert!(stats_str.contains("Primal Heuristics"));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize_summary() {
+        let model = Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .read_prob("data/test/simple.lp")
+            .unwrap()
+            .solve();
+
+        let json = serde_json::to_value(model.summary()).unwrap();
+        assert_eq!(json["status"], "Optimal");
+        assert_eq!(json["obj_val"], 200.);
+    }
+}
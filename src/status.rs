@@ -3,6 +3,7 @@ use scip_sys::SCIP_Status;
 
 /// An enum representing the status of a SCIP optimization run.
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Status {
     /// The solving status is not yet known.
     Unknown,
@@ -38,6 +39,27 @@ pub enum Status {
     Terminate,
 }
 
+impl Status {
+    /// Returns whether the solving process was stopped by a limit or an interrupt, i.e., it can be continued by
+    /// raising the limit and calling `solve` again.
+    pub fn is_limit(&self) -> bool {
+        matches!(
+            self,
+            Status::UserInterrupt
+                | Status::NodeLimit
+                | Status::TotalNodeLimit
+                | Status::StallNodeLimit
+                | Status::TimeLimit
+                | Status::MemoryLimit
+                | Status::GapLimit
+                | Status::SolutionLimit
+                | Status::BestSolutionLimit
+                | Status::RestartLimit
+                | Status::Terminate
+        )
+    }
+}
+
 impl From<SCIP_Status> for Status {
     /// Converts a u32 value to a `Status` enum variant.
     fn from(val: SCIP_Status) -> Self {
@@ -80,6 +102,7 @@ mod tests {
             .solve();
 
         assert_eq!(model.status(), Status::TimeLimit);
+        assert!(model.status().is_limit());
     }
 
     #[test]
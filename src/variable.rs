@@ -103,10 +103,17 @@ impl Variable {
     pub fn sol_val(&self) -> f64 {
         unsafe { ffi::SCIPgetVarSol(self.scip.raw, self.raw) }
     }
+
+    /// Returns the reduced cost of the variable in the LP of the current node, or `None` if the variable is not a
+    /// column of the LP or the reduced cost is not available, see [`Col::redcost`].
+    pub fn redcost(&self) -> Option<f64> {
+        self.col().and_then(|col| col.redcost())
+    }
 }
 
 /// The type of variable in an optimization problem.
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum VarType {
     /// The variable is a continuous variable.
     Continuous,
@@ -143,6 +150,7 @@ impl From<ffi::SCIP_Vartype> for VarType {
 
 /// An enum representing the status of a SCIP variable.
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum VarStatus {
     /// The variable is an original variable in the problem.
     Original,